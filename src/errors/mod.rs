@@ -1 +1,2 @@
+pub mod run_error;
 pub mod standard_error;
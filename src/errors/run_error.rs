@@ -0,0 +1,38 @@
+use crate::errors::standard_error::StandardError;
+use std::{error::Error, fmt::Display};
+
+// what a run stopped with: every syntax error found, or the one error the program hit
+#[derive(Debug, Clone)]
+pub struct RunError {
+    pub errors: Vec<StandardError>,
+}
+
+impl From<StandardError> for RunError {
+    fn from(error: StandardError) -> Self {
+        Self {
+            errors: vec![error],
+        }
+    }
+}
+
+impl From<Vec<StandardError>> for RunError {
+    fn from(errors: Vec<StandardError>) -> Self {
+        Self { errors }
+    }
+}
+
+// lets code embedding maid pass a failed run on with '?', like a single error
+impl Error for RunError {}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let output = self
+            .errors
+            .iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        write!(f, "{output}")
+    }
+}
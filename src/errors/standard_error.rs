@@ -1,29 +1,119 @@
-use crate::lexing::position::Position;
+use crate::{interpreting::context::Context, lexing::position::Position};
 use simply_colored::*;
-use std::fmt::Display;
+use std::{
+    cell::RefCell,
+    error::Error,
+    fmt::{Debug, Display},
+    rc::Rc,
+};
+
+// most calls a traceback lists before the middle of it is skipped, for deep recursion
+const MAX_TRACEBACK_FRAMES: usize = 10;
 
 #[derive(Clone)]
 pub struct StandardError {
     pub text: String,
-    pub pos_start: Position,
-    pub pos_end: Position,
-    pub help: Option<String>,
+    // boxed to keep errors small, parsing deeply nested code holds a lot of them on the stack
+    // and most results carry room for one
+    pub pos_start: Box<Position>,
+    pub pos_end: Box<Position>,
+    pub help: Option<Box<str>>,
+    // boxed for the same reason, errors get returned from just about everywhere
+    pub traceback: Box<Traceback>,
+}
+
+// what led up to an error
+#[derive(Clone, Default)]
+pub struct Traceback {
+    // files fetched on the way to the error, when it happened inside a module
+    pub import_chain: Vec<String>,
+    // the function calls the error happened in, innermost first, each named along with where
+    // it was called from. kept as text so errors can be sent to other threads
+    pub call_stack: Vec<(String, Option<String>)>,
+}
+
+// a position as 'file:line:column', the way tracebacks show it
+fn location(pos: &Position) -> String {
+    format!("{}:{}:{}", pos.filename, pos.line_num + 1, pos.column_num)
 }
 
 impl StandardError {
     pub fn new(text: &str, pos_start: Position, pos_end: Position, help: Option<&str>) -> Self {
         Self {
             text: text.to_string(),
-            pos_start,
-            pos_end,
-            help: if help.is_some() {
-                Some(help.unwrap().to_string())
-            } else {
-                None
-            },
+            pos_start: Box::new(pos_start),
+            pos_end: Box::new(pos_end),
+            help: help.map(Box::from),
+            traceback: Box::default(),
         }
     }
 
+    // records the call the error happened in and every call that led to it
+    pub fn set_call_stack(&mut self, context: &Rc<RefCell<Context>>) {
+        let mut context = Some(context.clone());
+
+        while let Some(current) = context {
+            let current = current.borrow();
+            let name = match current.display_name.as_str() {
+                "" => "<anonymous>",
+                name => name,
+            };
+
+            let (entry, parent) = match (&current.parent, &current.parent_entry_pos) {
+                (Some(parent), Some(entry_pos)) => {
+                    (Some(location(entry_pos)), Some(parent.clone()))
+                }
+                _ => (None, None),
+            };
+
+            self.traceback.call_stack.push((name.to_string(), entry));
+            context = parent;
+        }
+    }
+
+    // where the error happened followed by each call that led there, innermost first, like
+    // 'main.maid:10:5 (from greet) -> main.maid:3:2 (from <program>)'
+    pub fn build_traceback(&self) -> String {
+        let mut frames = vec![location(&self.pos_start)];
+
+        for (name, entry) in &self.traceback.call_stack {
+            frames.last_mut().unwrap().push_str(&format!(" (from {name})"));
+
+            if let Some(entry) = entry {
+                frames.push(entry.clone());
+            }
+        }
+
+        // a function calling itself from the same spot shows up once, with a count
+        let mut collapsed: Vec<(String, usize)> = Vec::new();
+
+        for frame in frames {
+            match collapsed.last_mut() {
+                Some((last, count)) if *last == frame => *count += 1,
+                _ => collapsed.push((frame, 1)),
+            }
+        }
+
+        let mut frames: Vec<String> = collapsed
+            .into_iter()
+            .map(|(frame, count)| match count {
+                1 => frame,
+                count => format!("{frame} x{count}"),
+            })
+            .collect();
+
+        if frames.len() > MAX_TRACEBACK_FRAMES {
+            let skipped = frames.len() - MAX_TRACEBACK_FRAMES + 1;
+            let outermost = frames.pop().unwrap();
+
+            frames.truncate(MAX_TRACEBACK_FRAMES - 2);
+            frames.push(format!("... {skipped} more"));
+            frames.push(outermost);
+        }
+
+        frames.join(" -> ")
+    }
+
     pub fn format_code_as_messup(
         &self,
         text: &str,
@@ -67,20 +157,38 @@ impl StandardError {
     }
 }
 
+// positions are shown through the traceback, printing them would include the whole file
+impl Debug for StandardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StandardError")
+            .field("text", &self.text)
+            .field("traceback", &self.build_traceback())
+            .field("help", &self.help)
+            .field("import_chain", &self.traceback.import_chain)
+            .finish()
+    }
+}
+
+// lets code embedding maid pass errors on with '?', into a Box<dyn Error> for one
+impl Error for StandardError {}
+
 impl Display for StandardError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut output = String::new();
         output.push_str(
             format!(
-                "{DIM_RED}{BOLD}error:{RESET} {}\n   in: {}:{}:{}",
+                "{DIM_RED}{BOLD}error:{RESET} {}\n   in: {}",
                 self.text,
-                self.pos_start.filename,
-                self.pos_start.line_num + 1,
-                self.pos_start.column_num,
+                self.build_traceback(),
             )
             .as_str(),
         );
 
+        if !self.traceback.import_chain.is_empty() {
+            let via = self.traceback.import_chain.join(" -> ");
+            output.push_str(format!("\n   via: {via}").as_str());
+        }
+
         // this will print the '^' indicating where the issue is
         output.push_str(
             format!(
@@ -0,0 +1,665 @@
+use crate::{
+    lexing::{comment::Comment, lexer::Lexer, token::Token, token_type::TokenType},
+    nodes::{
+        ast_node::AstNode, binary_operator_node::BinaryOperatorNode,
+        function_definition_node::FunctionDefinitionNode, if_node::IfNode, list_node::ListNode,
+        match_node::MatchNode, unary_operator_node::UnaryOperatorNode,
+    },
+};
+use std::collections::VecDeque;
+
+// how tightly each kind of expression binds, following the levels of the parser. an operand
+// binding looser than its spot allows gets wrapped in parentheses
+const LOOSEST: u8 = 0;
+const LOGICAL: u8 = 1;
+const NOT: u8 = 2;
+const COMPARISON: u8 = 3;
+const COALESCE: u8 = 4;
+const ARITHMETIC: u8 = 5;
+const TERM: u8 = 6;
+const FACTOR: u8 = 7;
+const POWER: u8 = 8;
+const CALL: u8 = 9;
+const ATOM: u8 = 10;
+
+const INDENT: &str = "    ";
+// lists and maps longer than this are written one element per line
+const MAX_INLINE_WIDTH: usize = 80;
+
+// prints a parsed program back out as source, laid out the same way every time: 4 space
+// indents, a space around binary operators and a blank line between top-level functions.
+// comments kept by the lexer are put back in front of the statement that followed them
+pub struct Formatter {
+    chars: Vec<char>,
+    lines: Vec<String>,
+    comments: VecDeque<Comment>,
+    depth: usize,
+}
+
+impl Formatter {
+    pub fn new(source: &str, comments: Vec<Comment>) -> Self {
+        Self {
+            chars: source.chars().collect(),
+            lines: source.lines().map(String::from).collect(),
+            comments: comments.into(),
+            depth: 0,
+        }
+    }
+
+    pub fn format(&mut self, program: &AstNode) -> String {
+        let lines = match program {
+            AstNode::List(list) => self.statements(list, isize::MAX, true),
+            node => vec![self.statement(node)],
+        };
+
+        if lines.is_empty() {
+            return String::new();
+        }
+
+        lines.join("\n") + "\n"
+    }
+
+    // a single expression on its own, like the default value of a function argument
+    pub fn expression(&mut self, node: &AstNode) -> String {
+        self.expr(node, LOOSEST)
+    }
+
+    // the lines of a block of statements, up to the line its '}' is on
+    fn statements(&mut self, list: &ListNode, closing_line: isize, top_level: bool) -> Vec<String> {
+        let mut lines: Vec<String> = Vec::new();
+        let mut after_function = false;
+        let mut last_line = -1;
+
+        for statement in list.element_nodes.iter() {
+            let line_num = statement.position_start().map_or(-1, |pos| pos.line_num);
+            let is_function = top_level && Self::defines_function(statement);
+
+            self.comments_after_code(line_num, &mut lines);
+
+            // the blank line goes above any comments describing the function
+            let spaced = is_function || after_function;
+
+            if spaced && lines.last().is_some_and(|line| !line.is_empty()) {
+                lines.push(String::new());
+            }
+
+            self.comments_before(line_num, &mut lines);
+
+            // statements sharing a line with a ';' between them each get a line of their own,
+            // without blank lines in between
+            if line_num != last_line {
+                self.blank_line_before(line_num, &mut lines);
+            }
+
+            let text = self.statement(statement);
+            lines.push(format!("{}{text}", self.indent()));
+
+            after_function = is_function;
+            last_line = line_num;
+        }
+
+        self.comments_before(closing_line, &mut lines);
+
+        lines
+    }
+
+    // the comments written after the code of the last statement, before the next one starts
+    fn comments_after_code(&mut self, line_num: isize, lines: &mut Vec<String>) {
+        while self
+            .comments
+            .front()
+            .is_some_and(|comment| comment.after_code && comment.line_num < line_num)
+        {
+            let comment = self.comments.pop_front().unwrap();
+            self.place_comment(comment, lines);
+        }
+    }
+
+    fn comments_before(&mut self, line_num: isize, lines: &mut Vec<String>) {
+        while self.comments.front().is_some_and(|comment| comment.line_num < line_num) {
+            let comment = self.comments.pop_front().unwrap();
+            self.place_comment(comment, lines);
+        }
+    }
+
+    // a comment goes on a line of its own, unless it was written after code, in which case it
+    // stays at the end of that code
+    fn place_comment(&self, comment: Comment, lines: &mut Vec<String>) {
+        match lines.last_mut() {
+            Some(last) if comment.after_code && !last.is_empty() => {
+                last.push(' ');
+                last.push_str(&comment.text);
+            }
+            _ => {
+                self.blank_line_before(comment.line_num, lines);
+                lines.push(format!("{}{}", self.indent(), comment.text));
+            }
+        }
+    }
+
+    // keeps one blank line where the source had any, runs of them are squashed together
+    fn blank_line_before(&self, line_num: isize, lines: &mut Vec<String>) {
+        let blank_above = line_num > 0
+            && self
+                .lines
+                .get(line_num as usize - 1)
+                .is_some_and(|line| line.trim().is_empty());
+
+        if blank_above && lines.last().is_some_and(|line| !line.is_empty()) {
+            lines.push(String::new());
+        }
+    }
+
+    fn indent(&self) -> String {
+        INDENT.repeat(self.depth)
+    }
+
+    fn defines_function(node: &AstNode) -> bool {
+        match node {
+            AstNode::FunctionDefinition(node) => node.var_name_token.is_some(),
+            AstNode::Export(node) => Self::defines_function(&node.node_to_export),
+            _ => false,
+        }
+    }
+
+    // statements ending in a '}' of their own don't get a ';' after them
+    fn ends_in_block(node: &AstNode) -> bool {
+        match node {
+            AstNode::If(node) => !Self::is_inline_if(node),
+            AstNode::Export(node) => Self::ends_in_block(&node.node_to_export),
+            AstNode::FunctionDefinition(_)
+            | AstNode::For(_)
+            | AstNode::ForIn(_)
+            | AstNode::While(_)
+            | AstNode::TryExcept(_)
+            | AstNode::Match(_)
+            | AstNode::StructDef(_) => true,
+            _ => false,
+        }
+    }
+
+    fn statement(&mut self, node: &AstNode) -> String {
+        let text = self.expr(node, LOOSEST);
+
+        if Self::ends_in_block(node) {
+            text
+        } else {
+            text + ";"
+        }
+    }
+
+    // a '{ ... }' body, indented one level deeper than the line it starts on
+    fn block(&mut self, body: &AstNode) -> String {
+        let AstNode::List(list) = body else {
+            return format!("{{ {} }}", self.statement(body));
+        };
+
+        let closing_line = list.pos_end.as_ref().map_or(-1, |pos| pos.line_num);
+
+        self.depth += 1;
+        let lines = self.statements(list, closing_line, false);
+        self.depth -= 1;
+
+        if lines.is_empty() {
+            return "{}".to_string();
+        }
+
+        format!("{{\n{}\n{}}}", lines.join("\n"), self.indent())
+    }
+
+    fn precedence(node: &AstNode) -> u8 {
+        match node {
+            AstNode::BinaryOperator(node) => Self::operator_precedence(&node.op_token),
+            AstNode::UnaryOperator(node) if node.op_token.matches(TokenType::TT_KEYWORD, "not") => {
+                NOT
+            }
+            AstNode::UnaryOperator(_) => FACTOR,
+            AstNode::MethodCall(_) => POWER,
+            AstNode::Call(_) | AstNode::PropertyAccess(_) => CALL,
+            AstNode::If(node) if Self::is_inline_if(node) => LOOSEST,
+            AstNode::VariableAssign(_)
+            | AstNode::ConstAssign(_)
+            | AstNode::CompoundAssign(_)
+            | AstNode::PropertyAssign(_)
+            | AstNode::Import(_)
+            | AstNode::Return(_)
+            | AstNode::Export(_) => LOOSEST,
+            _ => ATOM,
+        }
+    }
+
+    fn operator_precedence(op_token: &Token) -> u8 {
+        match op_token.token_type {
+            TokenType::TT_KEYWORD => match op_token.value.as_deref() {
+                Some("and" | "or") => LOGICAL,
+                _ => COMPARISON,
+            },
+            TokenType::TT_EE
+            | TokenType::TT_NE
+            | TokenType::TT_LT
+            | TokenType::TT_GT
+            | TokenType::TT_LTE
+            | TokenType::TT_GTE => COMPARISON,
+            TokenType::TT_COALESCE => COALESCE,
+            TokenType::TT_PLUS | TokenType::TT_MINUS => ARITHMETIC,
+            TokenType::TT_MUL | TokenType::TT_DIV | TokenType::TT_MOD => TERM,
+            _ => POWER,
+        }
+    }
+
+    fn operator(op_token: &Token) -> String {
+        match op_token.token_type {
+            TokenType::TT_PLUS => "+",
+            TokenType::TT_MINUS => "-",
+            TokenType::TT_MUL => "*",
+            TokenType::TT_DIV => "/",
+            TokenType::TT_POW => "^",
+            TokenType::TT_MOD => "%",
+            TokenType::TT_EE => "==",
+            TokenType::TT_NE => "!=",
+            TokenType::TT_LT => "<",
+            TokenType::TT_GT => ">",
+            TokenType::TT_LTE => "<=",
+            TokenType::TT_GTE => ">=",
+            TokenType::TT_COALESCE => "??",
+            TokenType::TT_PLUS_EQ => "+=",
+            TokenType::TT_MINUS_EQ => "-=",
+            TokenType::TT_MUL_EQ => "*=",
+            TokenType::TT_DIV_EQ => "/=",
+            TokenType::TT_POW_EQ => "^=",
+            TokenType::TT_MOD_EQ => "%=",
+            _ => return op_token.value.clone().unwrap_or_default(),
+        }
+        .to_string()
+    }
+
+    fn is_inline_if(node: &IfNode) -> bool {
+        node.cases.first().is_some_and(|(_, _, is_block)| !is_block)
+    }
+
+    fn name(token: &Token) -> String {
+        token.value.clone().unwrap_or_default()
+    }
+
+    // whether the source text at a token's start begins with 'text'
+    fn source_starts_with(&self, token: &Token, text: &str) -> bool {
+        let Some(start) = token.pos_start.as_ref().map(|pos| pos.index as usize) else {
+            return false;
+        };
+
+        text.chars().enumerate().all(|(i, c)| self.chars.get(start + i) == Some(&c))
+    }
+
+    // a number the way it was written, like '0xff' or '1_000', when that still reads as the
+    // same number. falls back to its decimal value
+    fn number(&self, token: &Token) -> String {
+        let start = token.pos_start.as_ref().map_or(0, |pos| pos.index as usize);
+        let mut spelling = String::new();
+
+        for (i, c) in self.chars.iter().enumerate().skip(start) {
+            let exponent_sign = matches!(c, '+' | '-')
+                && spelling.ends_with(['e', 'E'])
+                && !spelling.starts_with("0x");
+
+            if !(c.is_ascii_alphanumeric() || matches!(c, '_' | '.') || exponent_sign) {
+                break;
+            }
+
+            // a '.' only belongs to the number when a digit follows it
+            if *c == '.' && !self.chars.get(i + 1).is_some_and(char::is_ascii_digit) {
+                break;
+            }
+
+            spelling.push(*c);
+        }
+
+        let same = Lexer::new("", spelling.clone())
+            .make_tokens()
+            .is_ok_and(|tokens| tokens.len() == 2 && tokens[0].value == token.value);
+
+        if same {
+            spelling
+        } else {
+            Self::name(token)
+        }
+    }
+
+    fn string(&self, token: &Token) -> String {
+        let value = Self::name(token);
+
+        if self.source_starts_with(token, "\"\"\"") {
+            return format!("\"\"\"{value}\"\"\"");
+        }
+
+        format!("\"{}\"", escape(&value))
+    }
+
+    // a map key written as a bare name stays one, like {name: "maid"}
+    fn map_key(&mut self, key: &AstNode) -> String {
+        match key {
+            AstNode::Strings(node) if !self.source_starts_with(&node.token, "\"") => {
+                Self::name(&node.token)
+            }
+            key => self.expr(key, LOOSEST),
+        }
+    }
+
+    // elements between brackets, on one line when they fit and one per line when they don't
+    fn elements(&mut self, open: &str, close: &str, elements: &[String]) -> String {
+        let inline = elements.join(", ");
+
+        if inline.contains('\n') || self.indent().len() + inline.len() <= MAX_INLINE_WIDTH {
+            return format!("{open}{inline}{close}");
+        }
+
+        let inner = INDENT.repeat(self.depth + 1);
+        let lines: Vec<String> = elements.iter().map(|element| format!("{inner}{element}")).collect();
+
+        format!("{open}\n{}\n{}{close}", lines.join(",\n"), self.indent())
+    }
+
+    // an expression in a spot that needs it to bind at least as tightly as 'min'
+    fn expr(&mut self, node: &AstNode, min: u8) -> String {
+        let text = self.format_node(node);
+
+        if Self::precedence(node) < min {
+            format!("({text})")
+        } else {
+            text
+        }
+    }
+
+    // what comes before a '.' or a call's '(', which can't be another call
+    fn callee(&mut self, node: &AstNode) -> String {
+        match node {
+            AstNode::PropertyAccess(_) => self.format_node(node),
+            _ => self.expr(node, ATOM),
+        }
+    }
+
+    fn format_node(&mut self, node: &AstNode) -> String {
+        match node {
+            AstNode::BinaryOperator(node) => self.format_binary_operator_node(node),
+            AstNode::Bool(node) => Self::name(&node.token),
+            AstNode::Break(_) => "leave".to_string(),
+            AstNode::Call(node) => {
+                let callee = self.callee(&node.node_to_call);
+
+                format!("{callee}({})", self.arguments(&node.arg_nodes))
+            }
+            AstNode::CompoundAssign(node) => format!(
+                "{} {} {}",
+                Self::name(&node.var_name_token),
+                Self::operator(&node.op_token),
+                self.expr(&node.value_node, LOOSEST)
+            ),
+            AstNode::ConstAssign(node) => format!(
+                "stay {} = {}",
+                Self::name(&node.const_name_token),
+                self.expr(&node.value_node, LOOSEST)
+            ),
+            AstNode::Continue(_) => "next".to_string(),
+            AstNode::Export(node) => format!("export {}", self.format_node(&node.node_to_export)),
+            AstNode::For(node) => {
+                let mut text = format!(
+                    "walk {} = {} {} {}",
+                    Self::name(&node.var_name_token),
+                    self.expr(&node.start_value_node, LOOSEST),
+                    if node.inclusive { "to" } else { "through" },
+                    self.expr(&node.end_value_node, LOOSEST)
+                );
+
+                if let Some(step) = &node.step_value_node {
+                    text += &format!(" step = {}", self.expr(step, LOOSEST));
+                }
+
+                format!("{text} {}", self.block(&node.body_node))
+            }
+            AstNode::ForIn(node) => format!(
+                "walk {} in {} {}",
+                Self::name(&node.var_name_token),
+                self.expr(&node.iterable_node, LOOSEST),
+                self.block(&node.body_node)
+            ),
+            AstNode::FunctionDefinition(node) => self.format_function_definition_node(node),
+            AstNode::If(node) => self.format_if_node(node),
+            AstNode::Import(node) => {
+                let mut text = format!("fetch {}", self.expr(&node.node_to_import, LOOSEST));
+
+                if let Some(alias) = &node.alias_token {
+                    text += &format!(" as {}", Self::name(alias));
+                }
+
+                text
+            }
+            AstNode::InterpString(node) => {
+                let mut text = String::from("\"");
+
+                for segment in node.segments.iter() {
+                    match segment.as_ref() {
+                        AstNode::Strings(literal) => text += &escape(&Self::name(&literal.token)),
+                        expression => text += &format!("{{{}}}", self.expr(expression, LOOSEST)),
+                    }
+                }
+
+                text + "\""
+            }
+            AstNode::List(node) => {
+                let elements: Vec<String> =
+                    node.element_nodes.iter().map(|node| self.expr(node, LOOSEST)).collect();
+
+                self.elements("[", "]", &elements)
+            }
+            AstNode::Map(node) => {
+                let entries: Vec<String> = node
+                    .entry_nodes
+                    .iter()
+                    .map(|(key, value)| {
+                        format!("{}: {}", self.map_key(key), self.expr(value, LOOSEST))
+                    })
+                    .collect();
+
+                self.elements("{", "}", &entries)
+            }
+            AstNode::Match(node) => self.format_match_node(node),
+            AstNode::MethodCall(node) => format!(
+                "{} ^ {}({})",
+                self.expr(&node.receiver_node, CALL),
+                self.expr(&node.method_node, ATOM),
+                self.arguments(&node.arg_nodes)
+            ),
+            AstNode::Null(_) => "null".to_string(),
+            AstNode::Number(node) => self.number(&node.token),
+            AstNode::PropertyAccess(node) => {
+                format!("{}.{}", self.callee(&node.object), node.property)
+            }
+            AstNode::PropertyAssign(node) => format!(
+                "obj {}.{} = {}",
+                Self::name(&node.var_name_token),
+                Self::name(&node.property_token),
+                self.expr(&node.value_node, LOOSEST)
+            ),
+            AstNode::Return(node) => match &node.node_to_return {
+                Some(value) => format!("give {}", self.expr(value, LOOSEST)),
+                None => "give".to_string(),
+            },
+            AstNode::Strings(node) => self.string(&node.token),
+            AstNode::StructDef(node) => {
+                let fields: Vec<String> = node.field_name_tokens.iter().map(Self::name).collect();
+
+                if fields.is_empty() {
+                    format!("struct {} {{}}", Self::name(&node.name_token))
+                } else {
+                    format!("struct {} {{ {} }}", Self::name(&node.name_token), fields.join(", "))
+                }
+            }
+            AstNode::TryExcept(node) => format!(
+                "unsafe {} safe {} {}",
+                self.block(&node.try_body_node),
+                Self::name(&node.error_name_token),
+                self.block(&node.except_body_node)
+            ),
+            AstNode::UnaryOperator(node) => self.format_unary_operator_node(node),
+            AstNode::VariableAccess(node) => Self::name(&node.var_name_token),
+            AstNode::VariableAssign(node) => format!(
+                "obj {} = {}",
+                Self::name(&node.var_name_token),
+                self.expr(&node.value_node, LOOSEST)
+            ),
+            AstNode::While(node) => format!(
+                "{} {} {}",
+                if node.until { "until" } else { "while" },
+                self.expr(&node.condition_node, LOOSEST),
+                self.block(&node.body_node)
+            ),
+        }
+    }
+
+    fn arguments(&mut self, nodes: &[AstNode]) -> String {
+        let arguments: Vec<String> = nodes.iter().map(|node| self.expr(node, LOOSEST)).collect();
+
+        arguments.join(", ")
+    }
+
+    // operators group from the left, except '^' which groups from the right
+    fn format_binary_operator_node(&mut self, node: &BinaryOperatorNode) -> String {
+        let precedence = Self::operator_precedence(&node.op_token);
+
+        let (left_min, right_min) = match precedence {
+            LOGICAL => (LOGICAL, NOT),
+            POWER => (CALL, FACTOR),
+            _ => (precedence, precedence + 1),
+        };
+
+        format!(
+            "{} {} {}",
+            self.expr(&node.left_node, left_min),
+            Self::operator(&node.op_token),
+            self.expr(&node.right_node, right_min)
+        )
+    }
+
+    fn format_unary_operator_node(&mut self, node: &UnaryOperatorNode) -> String {
+        if node.op_token.matches(TokenType::TT_KEYWORD, "not") {
+            return format!("not {}", self.expr(&node.node, NOT));
+        }
+
+        // '- -x' would read as a single operator, so the inner one gets parentheses
+        let operand = match node.node.as_ref() {
+            AstNode::UnaryOperator(_) => format!("({})", self.format_node(&node.node)),
+            operand => self.expr(operand, FACTOR),
+        };
+
+        format!("{}{operand}", Self::operator(&node.op_token))
+    }
+
+    fn format_function_definition_node(&mut self, node: &FunctionDefinitionNode) -> String {
+        let last = node.arg_name_tokens.len().saturating_sub(1);
+
+        let params: Vec<String> = node
+            .arg_name_tokens
+            .iter()
+            .enumerate()
+            .map(|(i, param)| match &param.default_node {
+                Some(default) => {
+                    format!("{} = {}", Self::name(&param.name_token), self.expr(default, LOOSEST))
+                }
+                None if node.is_variadic && i == last => {
+                    format!("...{}", Self::name(&param.name_token))
+                }
+                None => Self::name(&param.name_token),
+            })
+            .collect();
+
+        let name = node.var_name_token.as_ref().map(Self::name).unwrap_or_default();
+        let separator = if name.is_empty() { "" } else { " " };
+
+        format!("func{separator}{name}({}) {}", params.join(", "), self.block(&node.body_node))
+    }
+
+    fn format_if_node(&mut self, node: &IfNode) -> String {
+        if Self::is_inline_if(node) {
+            let (condition, then, _) = &node.cases[0];
+
+            // a nested inline 'if' would take the 'otherwise' for itself
+            let mut text = format!(
+                "if {} then {}",
+                self.expr(condition, LOOSEST),
+                self.expr(then, LOGICAL)
+            );
+
+            if let Some((otherwise, _)) = &node.else_case {
+                text += &format!(" otherwise {}", self.expr(otherwise, LOOSEST));
+            }
+
+            return text;
+        }
+
+        let mut cases: Vec<String> = Vec::new();
+
+        for (condition, body, _) in node.cases.iter() {
+            cases.push(format!("{} {}", self.expr(condition, LOOSEST), self.block(body)));
+        }
+
+        let mut text = format!("if {}", cases.join(" alsoif "));
+
+        if let Some((otherwise, _)) = &node.else_case {
+            text += &format!(" otherwise {}", self.block(otherwise));
+        }
+
+        text
+    }
+
+    fn format_match_node(&mut self, node: &MatchNode) -> String {
+        let subject = self.expr(&node.subject_node, LOOSEST);
+
+        self.depth += 1;
+
+        let mut cases: Vec<String> = Vec::new();
+
+        for (pattern, body) in node.cases.iter() {
+            let case = format!("{} -> {}", self.expr(pattern, LOOSEST), self.expr(body, LOOSEST));
+            cases.push(format!("{}{case}", self.indent()));
+        }
+
+        if let Some(default) = &node.default_case {
+            let case = format!("_ -> {}", self.expr(default, LOOSEST));
+            cases.push(format!("{}{case}", self.indent()));
+        }
+
+        self.depth -= 1;
+
+        if cases.is_empty() {
+            return format!("match {subject} {{}}");
+        }
+
+        format!("match {subject} {{\n{}\n{}}}", cases.join("\n"), self.indent())
+    }
+}
+
+// writes a string's value back as the inside of a string literal. braces get escaped so they
+// aren't read as interpolation, apart from the '{}' placeholders 'servef' fills in
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            '\x1b' if chars.peek() == Some(&'[') => escaped.push_str("\\e"),
+            '{' if chars.peek() == Some(&'}') => {
+                chars.next();
+                escaped.push_str("{}");
+            }
+            '{' => escaped.push_str("\\{"),
+            '}' => escaped.push_str("\\}"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
@@ -7,6 +7,9 @@ pub struct Context {
     pub parent: Option<Rc<RefCell<Context>>>,
     pub parent_entry_pos: Option<Position>,
     pub symbol_table: Option<Rc<RefCell<SymbolTable>>>,
+    pub exports: Vec<String>,
+    // names merged in by a plain 'fetch', kept out of 'fetch ... as' namespaces
+    pub imports: Vec<String>,
 }
 
 impl Context {
@@ -20,6 +23,8 @@ impl Context {
             parent,
             parent_entry_pos,
             symbol_table: None,
+            exports: Vec::new(),
+            imports: Vec::new(),
         }
     }
 }
@@ -1,49 +1,122 @@
 use crate::{
     errors::standard_error::StandardError,
-    interpreting::{context::Context, runtime_result::RuntimeResult, symbol_table::SymbolTable},
+    interpreting::{
+        context::Context, limits::Limits, runtime_result::RuntimeResult, symbol_table::SymbolTable,
+    },
     lexing::{lexer::Lexer, token_type::TokenType},
     nodes::{
-        ast_node::AstNode, binary_operator_node::BinaryOperatorNode, break_node::BreakNode,
-        call_node::CallNode, const_assign_node::ConstAssignNode, continue_node::ContinueNode,
-        for_node::ForNode, function_definition_node::FunctionDefinitionNode, if_node::IfNode,
-        import_node::ImportNode, list_node::ListNode, number_node::NumberNode,
-        return_node::ReturnNode, string_node::StringNode, try_except_node::TryExceptNode,
-        unary_operator_node::UnaryOperatorNode, variable_access_node::VariableAccessNode,
-        variable_assign_node::VariableAssignNode, while_node::WhileNode,
+        ast_node::AstNode, binary_operator_node::BinaryOperatorNode, bool_node::BoolNode,
+        break_node::BreakNode, call_node::CallNode, compound_assign_node::CompoundAssignNode,
+        const_assign_node::ConstAssignNode, continue_node::ContinueNode, export_node::ExportNode,
+        for_in_node::ForInNode, for_node::ForNode, function_definition_node::FunctionDefinitionNode,
+        if_node::IfNode, import_node::ImportNode, interp_string_node::InterpStringNode,
+        list_node::ListNode, map_node::MapNode, match_node::MatchNode,
+        method_call_node::MethodCallNode, null_node::NullNode, number_node::NumberNode,
+        property_access_node::PropertyAccessNode, property_assign_node::PropertyAssignNode,
+        return_node::ReturnNode, string_node::StringNode, struct_def_node::StructDefNode,
+        try_except_node::TryExceptNode, unary_operator_node::UnaryOperatorNode,
+        variable_access_node::VariableAccessNode, variable_assign_node::VariableAssignNode,
+        while_node::WhileNode,
     },
     parsing::parser::Parser,
+    syntax::attributes::{BUILT_INS, BUILT_IN_NUMBERS, FILE_SYMBOLS, MAX_NESTING_DEPTH},
     values::{
-        built_in_function::BuiltInFunction, function::Function, list::List, number::Number,
-        string::Str, value::Value,
+        bool::Bool, built_in_function::BuiltInFunction, function::Function, list::List,
+        map::Map, null::Null, number::Number, string::Str, struct_def::StructDef,
+        value::Value,
     },
 };
-use std::{cell::RefCell, fs, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    time::Instant,
+};
 
 pub struct Interpreter {
     pub global_symbol_table: Rc<RefCell<SymbolTable>>,
+    // how many nodes are being visited inside each other. every call runs in an interpreter of
+    // its own, so recursion counts towards call_depth instead
+    pub depth: usize,
+    // how many calls this interpreter's run is inside of
+    pub call_depth: usize,
+    pub limits: Limits,
+    // operations the run may still perform, shared with the interpreters its calls and modules
+    // run in. 'None' means there's no limit
+    pub operations_left: Rc<Cell<Option<usize>>>,
+    // when the run has to stop by
+    pub deadline: Option<Instant>,
+    // module contexts by canonical path, handed down to the interpreters running them
+    pub module_cache: HashMap<PathBuf, Rc<RefCell<Context>>>,
+    // canonical paths of the modules being fetched, outermost first
+    pub import_stack: Vec<PathBuf>,
 }
 
 impl Interpreter {
     pub fn new() -> Self {
-        let interpreter = Self {
-            global_symbol_table: Rc::new(RefCell::new(SymbolTable::new(None))),
-        };
-
-        let builtins = [
-            "serve", "process", "sweep", "stash", "tostring", "tonumber", "length", "uhoh", "type", "run",
-            "_env", "rest", "inline",
-        ];
+        let interpreter = Self::with_globals(Rc::new(RefCell::new(SymbolTable::new(None))));
 
-        for builtin in &builtins {
+        for builtin in BUILT_INS {
             interpreter.global_symbol_table.borrow_mut().set(
                 builtin.to_string(),
                 Some(Value::BuiltInFunction(BuiltInFunction::new(builtin))),
             );
         }
 
+        for (name, value) in BUILT_IN_NUMBERS {
+            interpreter
+                .global_symbol_table
+                .borrow_mut()
+                .set(name.to_string(), Some(Number::from(*value)));
+        }
+
         interpreter
     }
 
+    // shares an existing global table, built-ins included, instead of filling a new one
+    pub fn with_globals(global_symbol_table: Rc<RefCell<SymbolTable>>) -> Self {
+        Self {
+            global_symbol_table,
+            depth: 0,
+            call_depth: 0,
+            limits: Limits::default(),
+            operations_left: Rc::new(Cell::new(None)),
+            deadline: None,
+            module_cache: HashMap::new(),
+            import_stack: Vec::new(),
+        }
+    }
+
+    // sets an interpreter up to run a call, or a module or string of code, started by another
+    pub fn called_from(mut self, caller: &Interpreter) -> Self {
+        self.call_depth = caller.call_depth + 1;
+        self.limits = caller.limits;
+        self.operations_left = caller.operations_left.clone();
+        self.deadline = caller.deadline;
+        self
+    }
+
+    // holds what the interpreter runs from here on to the limits, for running untrusted scripts
+    pub fn limit(&mut self, limits: Limits) {
+        self.limits = limits;
+        self.operations_left = Rc::new(Cell::new(limits.max_operations));
+        self.deadline = limits.timeout.map(|timeout| Instant::now() + timeout);
+    }
+
+    // empties the tables of a finished run. a closure or fetched function kept in them holds on to
+    // a scope that leads back to them, so they'd never be dropped otherwise
+    pub fn release(&mut self) {
+        self.global_symbol_table.borrow_mut().symbols.clear();
+
+        for (_, module_context) in self.module_cache.drain() {
+            if let Some(symbol_table) = &module_context.borrow().symbol_table {
+                symbol_table.borrow_mut().symbols.clear();
+            }
+        }
+    }
+
     pub fn evaluate(&mut self, src: &str, context: Rc<RefCell<Context>>) -> Option<StandardError> {
         let mut lexer = Lexer::new("<eval>", src.to_string());
         let token_result = lexer.make_tokens();
@@ -55,8 +128,8 @@ impl Interpreter {
         let mut parser = Parser::new(&token_result.ok().unwrap());
         let ast = parser.parse();
 
-        if ast.error.is_some() {
-            return ast.error;
+        if ast.has_failed() {
+            return ast.errors.into_iter().next();
         }
 
         self.visit(ast.node.unwrap(), context);
@@ -64,7 +137,56 @@ impl Interpreter {
         None
     }
 
+    // what has run out of the operation budget and time limit, as the error's text and help
+    fn exceeded_limit(&self) -> Option<(&'static str, &'static str)> {
+        if let Some(left) = self.operations_left.get() {
+            if left == 0 {
+                return Some((
+                    "operation budget exhausted",
+                    "the script did more work than it was allowed to",
+                ));
+            }
+
+            self.operations_left.set(Some(left - 1));
+        }
+
+        if self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            return Some((
+                "time limit exceeded",
+                "the script ran for longer than it was allowed to",
+            ));
+        }
+
+        None
+    }
+
     pub fn visit(&mut self, node: Box<AstNode>, context: Rc<RefCell<Context>>) -> RuntimeResult {
+        if let Some((text, help)) = self.exceeded_limit() {
+            return RuntimeResult::new().failure(Some(StandardError::new(
+                text,
+                node.position_start().unwrap(),
+                node.position_end().unwrap(),
+                Some(help),
+            )));
+        }
+
+        if self.depth >= MAX_NESTING_DEPTH {
+            return RuntimeResult::new().failure(Some(StandardError::new(
+                "nesting too deep",
+                node.position_start().unwrap(),
+                node.position_end().unwrap(),
+                Some("split the expression up into smaller objects"),
+            )));
+        }
+
+        self.depth += 1;
+        let result = self.visit_node(node, context);
+        self.depth -= 1;
+
+        result
+    }
+
+    pub fn visit_node(&mut self, node: Box<AstNode>, context: Rc<RefCell<Context>>) -> RuntimeResult {
         match node.as_ref() {
             AstNode::List(node) => {
                 self.visit_list_node(node, context)
@@ -72,15 +194,27 @@ impl Interpreter {
             AstNode::Number(node) => {
                 self.visit_number_node(node, context)
             }
+            AstNode::Bool(node) => {
+                self.visit_bool_node(node, context)
+            }
+            AstNode::Null(node) => {
+                self.visit_null_node(node, context)
+            }
             AstNode::Strings(node) => {
                 self.visit_string_node(node, context)
             }
+            AstNode::InterpString(node) => {
+                self.visit_interp_string_node(node, context)
+            }
             AstNode::VariableAssign(node) => {
                 self.visit_variable_assign_node(node, context)
             }
             AstNode::ConstAssign(node) => {
                 self.visit_const_assign_node(node, context)
             }
+            AstNode::CompoundAssign(node) => {
+                self.visit_compound_assign_node(node, context)
+            }
             AstNode::VariableAccess(node) => {
                 self.visit_variable_access_node(node, context)
             }
@@ -90,15 +224,39 @@ impl Interpreter {
             AstNode::Import(node) => {
                 self.visit_import_node(node, context)
             }
+            AstNode::Export(node) => {
+                self.visit_export_node(node, context)
+            }
+            AstNode::Map(node) => {
+                self.visit_map_node(node, context)
+            }
+            AstNode::MethodCall(node) => {
+                self.visit_method_call_node(node, context)
+            }
+            AstNode::PropertyAccess(node) => {
+                self.visit_property_access_node(node, context)
+            }
+            AstNode::PropertyAssign(node) => {
+                self.visit_property_assign_node(node, context)
+            }
+            AstNode::StructDef(node) => {
+                self.visit_struct_def_node(node, context)
+            }
             AstNode::For(node) => {
                 self.visit_for_node(node, context)
             }
+            AstNode::ForIn(node) => {
+                self.visit_for_in_node(node, context)
+            }
             AstNode::While(node) => {
                 self.visit_while_node(node, context)
             }
             AstNode::TryExcept(node) => {
                 self.visit_try_except_node(node, context)
             }
+            AstNode::Match(node) => {
+                self.visit_match_node(node, context)
+            }
             AstNode::FunctionDefinition(node) => {
                 self.visit_function_definition_node(node, context)
             }
@@ -142,6 +300,24 @@ impl Interpreter {
         ))
     }
 
+    pub fn visit_bool_node(&self, node: &BoolNode, context: Rc<RefCell<Context>>) -> RuntimeResult {
+        let value = node.token.value.as_deref() == Some("true");
+
+        RuntimeResult::new().success(Some(
+            Bool::from(value)
+                .set_context(Some(context.clone()))
+                .set_position(node.pos_start.clone(), node.pos_end.clone()),
+        ))
+    }
+
+    pub fn visit_null_node(&self, node: &NullNode, context: Rc<RefCell<Context>>) -> RuntimeResult {
+        RuntimeResult::new().success(Some(
+            Value::NullValue(Null::new())
+                .set_context(Some(context.clone()))
+                .set_position(node.pos_start.clone(), node.pos_end.clone()),
+        ))
+    }
+
     pub fn visit_list_node(
         &mut self,
         node: &ListNode,
@@ -160,6 +336,16 @@ impl Interpreter {
             elements.push(element_result.unwrap());
         }
 
+        if !node.holds_statements {
+            if let Err(error) = self.limits.check_list_length(
+                elements.len(),
+                node.pos_start.clone().unwrap(),
+                node.pos_end.clone().unwrap(),
+            ) {
+                return result.failure(Some(error));
+            }
+        }
+
         result.success(Some(
             Value::ListValue(List::new(elements))
                 .set_context(Some(context.clone()))
@@ -167,6 +353,50 @@ impl Interpreter {
         ))
     }
 
+    pub fn visit_map_node(
+        &mut self,
+        node: &MapNode,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let mut entries: HashMap<String, Value> = HashMap::new();
+
+        for (key_node, value_node) in node.entry_nodes.iter() {
+            let key = result.register(self.visit(key_node.to_owned(), context.clone()));
+
+            if result.should_return() {
+                return result;
+            }
+
+            let key = match key.unwrap() {
+                Value::StringValue(string) => string.as_string(),
+                key => {
+                    return result.failure(Some(StandardError::new(
+                        "expected type string",
+                        key.position_start().unwrap(),
+                        key.position_end().unwrap(),
+                        Some("map keys have to be strings, use tostring() to convert other keys"),
+                    )));
+                }
+            };
+
+            let value = result.register(self.visit(value_node.to_owned(), context.clone()));
+
+            if result.should_return() {
+                return result;
+            }
+
+            // a repeated key keeps its last value
+            entries.insert(key, value.unwrap());
+        }
+
+        result.success(Some(
+            Map::from(entries)
+                .set_context(Some(context.clone()))
+                .set_position(node.pos_start.clone(), node.pos_end.clone()),
+        ))
+    }
+
     pub fn visit_string_node(
         &mut self,
         node: &StringNode,
@@ -179,6 +409,31 @@ impl Interpreter {
         ))
     }
 
+    pub fn visit_interp_string_node(
+        &mut self,
+        node: &InterpStringNode,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let mut string = String::new();
+
+        for segment in node.segments.iter() {
+            let value = result.register(self.visit(segment.to_owned(), context.clone()));
+
+            if result.should_return() {
+                return result;
+            }
+
+            string.push_str(&value.unwrap().as_string());
+        }
+
+        result.success(Some(
+            Value::StringValue(Str::new(string))
+                .set_context(Some(context.clone()))
+                .set_position(node.pos_start.clone(), node.pos_end.clone()),
+        ))
+    }
+
     pub fn visit_variable_assign_node(
         &mut self,
         node: &VariableAssignNode,
@@ -239,11 +494,70 @@ impl Interpreter {
             .as_mut()
             .unwrap()
             .borrow_mut()
-            .set(const_name, value.clone());
+            .set_constant(const_name, value.clone());
 
         result.success(value)
     }
 
+    pub fn visit_compound_assign_node(
+        &mut self,
+        node: &CompoundAssignNode,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let var_name = node.var_name_token.value.as_ref().unwrap().clone();
+
+        // reading it through a normal access errors on undefined names with the usual message
+        let current = result.register(self.visit_variable_access_node(
+            &VariableAccessNode::new(node.var_name_token.clone()),
+            context.clone(),
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let symbol_table = context.borrow().symbol_table.clone().unwrap();
+
+        if symbol_table.borrow().is_constant(&var_name) {
+            return result.failure(Some(StandardError::new(
+                "cannot reassign the value of a constant",
+                node.pos_start.as_ref().unwrap().to_owned(),
+                node.op_token.pos_end.as_ref().unwrap().to_owned(),
+                Some(
+                    format!("'{var_name}' was defined with 'stay', use 'obj' to allow changes")
+                        .as_str(),
+                ),
+            )));
+        }
+
+        let value = result.register(self.visit(node.value_node.clone(), context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let operator = match node.op_token.token_type {
+            TokenType::TT_PLUS_EQ => "+",
+            TokenType::TT_MINUS_EQ => "-",
+            TokenType::TT_MUL_EQ => "*",
+            TokenType::TT_DIV_EQ => "/",
+            TokenType::TT_POW_EQ => "^",
+            _ => "%",
+        };
+
+        let new_value = match self.perform_operation(current.unwrap(), operator, value.unwrap()) {
+            Ok(mut new_value) => {
+                new_value.set_position(node.pos_start.clone(), node.pos_end.clone())
+            }
+            Err(error) => return result.failure(Some(error)),
+        };
+
+        symbol_table.borrow_mut().set(var_name, Some(new_value.clone()));
+
+        result.success(Some(new_value))
+    }
+
     pub fn visit_variable_access_node(
         &mut self,
         node: &VariableAccessNode,
@@ -300,7 +614,7 @@ impl Interpreter {
                 }
 
                 return result.success(if *should_return_null {
-                    Some(Number::null_value())
+                    Some(Value::NullValue(Null::new()))
                 } else {
                     expr_value
                 });
@@ -316,13 +630,13 @@ impl Interpreter {
             }
 
             return result.success(if should_return_null {
-                Some(Number::null_value())
+                Some(Value::NullValue(Null::new()))
             } else {
                 else_value
             });
         }
 
-        result.success(Some(Number::null_value()))
+        result.success(Some(Value::NullValue(Null::new())))
     }
 
     pub fn visit_for_node(
@@ -334,9 +648,9 @@ impl Interpreter {
 
         let start_value = match result
             .register(self.visit(node.start_value_node.clone(), context.clone()))
-            .unwrap()
         {
-            Value::NumberValue(value) => Number::new(value.value),
+            Some(Value::NumberValue(value)) => Number::new(value.value),
+            None => return result,
             _ => {
                 return result.failure(Some(StandardError::new(
                     "expected start value as number",
@@ -353,9 +667,9 @@ impl Interpreter {
 
         let end_value = match result
             .register(self.visit(node.end_value_node.clone(), context.clone()))
-            .unwrap()
         {
-            Value::NumberValue(value) => Number::new(value.value),
+            Some(Value::NumberValue(value)) => Number::new(value.value),
+            None => return result,
             _ => {
                 return result.failure(Some(StandardError::new(
                     "expected end value as number",
@@ -378,9 +692,9 @@ impl Interpreter {
                     node.step_value_node.as_ref().unwrap().clone(),
                     context.clone(),
                 ))
-                .unwrap()
             {
-                Value::NumberValue(value) => Number::new(value.value),
+                Some(Value::NumberValue(value)) => Number::new(value.value),
+                None => return result,
                 _ => {
                     return result.failure(Some(StandardError::new(
                         "expected step value as number",
@@ -401,7 +715,7 @@ impl Interpreter {
         let mut i = start_value.value;
 
         if step_value.value >= 0.0 {
-            while i < end_value.value {
+            while i < end_value.value || (node.inclusive && i == end_value.value) {
                 context
                     .borrow_mut()
                     .symbol_table
@@ -432,7 +746,7 @@ impl Interpreter {
                 }
             }
         } else {
-            while i > end_value.value {
+            while i > end_value.value || (node.inclusive && i == end_value.value) {
                 context
                     .borrow_mut()
                     .symbol_table
@@ -464,7 +778,61 @@ impl Interpreter {
             }
         }
 
-        result.success(Some(Number::null_value()))
+        result.success(Some(Value::NullValue(Null::new())))
+    }
+
+    pub fn visit_for_in_node(
+        &mut self,
+        node: &ForInNode,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+
+        let iterable = result.register(self.visit(node.iterable_node.clone(), context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let elements = match iterable.unwrap() {
+            Value::ListValue(list) => list.elements,
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type list",
+                    node.iterable_node.position_start().unwrap(),
+                    node.iterable_node.position_end().unwrap(),
+                    Some("'walk <name> in <list>' goes through a list, use 'through' for a range"),
+                )));
+            }
+        };
+
+        let var_name = node.var_name_token.value.as_ref().unwrap().clone();
+        let symbol_table = context.borrow().symbol_table.clone().unwrap();
+
+        // the loop variable only lives for the loop, put back whatever it replaced afterwards
+        let replaced = symbol_table.borrow().symbols.get(&var_name).cloned();
+
+        for element in elements {
+            symbol_table.borrow_mut().set(var_name.clone(), Some(element));
+
+            let _ = result.register(self.visit(node.body_node.clone(), context.clone()));
+
+            // stop on 'leave', an error or a 'give' out of the surrounding function
+            if result.should_return() && !result.loop_should_continue {
+                break;
+            }
+        }
+
+        match replaced {
+            Some(value) => symbol_table.borrow_mut().set(var_name, value),
+            None => symbol_table.borrow_mut().remove(&var_name),
+        }
+
+        if result.error.is_some() || result.func_return_value.is_some() {
+            return result;
+        }
+
+        result.success(Some(Value::NullValue(Null::new())))
     }
 
     pub fn visit_while_node(
@@ -484,7 +852,7 @@ impl Interpreter {
 
             let condition = condition.unwrap();
 
-            if !condition.is_true() {
+            if condition.is_true() == node.until {
                 break;
             }
 
@@ -506,7 +874,7 @@ impl Interpreter {
             }
         }
 
-        result.success(Some(Number::null_value()))
+        result.success(Some(Value::NullValue(Null::new())))
     }
 
     pub fn visit_try_except_node(
@@ -544,7 +912,45 @@ impl Interpreter {
             return result;
         }
 
-        result.success(Some(Number::null_value()))
+        result.success(Some(Value::NullValue(Null::new())))
+    }
+
+    pub fn visit_match_node(
+        &mut self,
+        node: &MatchNode,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let subject = result.register(self.visit(node.subject_node.clone(), context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let subject = subject.unwrap();
+
+        for (pattern_node, body_node) in node.cases.iter() {
+            let pattern = result.register(self.visit(pattern_node.clone(), context.clone()));
+
+            if result.should_return() {
+                return result;
+            }
+
+            // values of different types can't be compared, so they just don't match
+            let is_match = subject
+                .clone()
+                .perform_operation("==", pattern.unwrap())
+                .is_ok_and(|is_eq| is_eq.is_true());
+
+            if is_match {
+                return self.visit(body_node.clone(), context);
+            }
+        }
+
+        match &node.default_case {
+            Some(default_case) => self.visit(default_case.clone(), context),
+            None => result.success(Some(Value::NullValue(Null::new()))),
+        }
     }
 
     pub fn visit_import_node(
@@ -561,7 +967,10 @@ impl Interpreter {
 
         let import = import.unwrap();
         let file_to_import = match import {
-            Value::StringValue(ref string) => string.as_string(),
+            Value::StringValue(ref string) => self.resolve_import_path(
+                &string.as_string(),
+                &import.position_start().unwrap().filename,
+            ),
             _ => {
                 return result.failure(Some(StandardError::new(
                     "expected type string",
@@ -581,80 +990,239 @@ impl Interpreter {
             )));
         }
 
-        if file_to_import == import.position_start().unwrap().filename {
+        // the files being fetched right now, starting with the program itself
+        let mut import_stack = self.import_stack.clone();
+
+        if import_stack.is_empty() {
+            import_stack.extend(fs::canonicalize(&import.position_start().unwrap().filename));
+        }
+
+        let cache_key = fs::canonicalize(&file_to_import).ok();
+
+        if let Some(start) = import_stack.iter().position(|path| Some(path) == cache_key.as_ref()) {
+            let cycle = import_stack[start..].iter().chain(cache_key.as_ref());
+
             return result.failure(Some(StandardError::new(
-                "circular import",
+                format!("circular import: {}", Self::import_chain(cycle).join(" -> ")).as_str(),
                 import.position_start().unwrap(),
                 import.position_end().unwrap(),
-                None,
+                Some("move what both files need into a file they can each fetch"),
             )));
         }
 
-        let mut contents = String::new();
+        import_stack.extend(cache_key.clone());
+
+        // a file only runs the first time it's fetched, later fetches reuse its symbols
+        let cached = cache_key.as_ref().and_then(|key| self.module_cache.get(key).cloned());
+
+        let module_context = match cached {
+            Some(module_context) => module_context,
+            None => {
+                let mut contents = String::new();
+
+                match fs::read_to_string(&file_to_import) {
+                    Ok(extra) => contents.push_str(&extra),
+                    Err(_) => {
+                        return result.failure(Some(StandardError::new(
+                            &format!(
+                                "file contents couldn't be read properly on {file_to_import}"
+                            ),
+                            import.position_start().unwrap(),
+                            import.position_end().unwrap(),
+                            Some("add a UTF-8 encoded '.maid' file you would like to import"),
+                        )));
+                    }
+                }
 
-        match fs::read_to_string(&file_to_import) {
-            Ok(extra) => contents.push_str(&extra),
-            Err(_) => {
-                return result.failure(Some(StandardError::new(
-                    &format!(
-                        "file contents couldn't be read properly on {file_to_import}"
-                    ),
-                    import.position_start().unwrap(),
-                    import.position_end().unwrap(),
-                    Some("add a UTF-8 encoded '.maid' file you would like to import"),
-                )));
-            }
-        }
+                let mut lexer = Lexer::new(&file_to_import, contents);
+                let token_result = lexer.make_tokens();
 
-        let mut lexer = Lexer::new(&file_to_import, contents);
-        let token_result = lexer.make_tokens();
+                if token_result.is_err() {
+                    return result.failure(Self::trace_import(token_result.err(), &import_stack));
+                }
 
-        if token_result.is_err() {
-            return result.failure(token_result.err());
-        }
+                let mut parser = Parser::new(&token_result.ok().unwrap());
+                let ast = parser.parse();
 
-        let mut parser = Parser::new(&token_result.ok().unwrap());
-        let ast = parser.parse();
+                if ast.has_failed() {
+                    return result.failure(Self::trace_import(ast.errors.into_iter().next(), &import_stack));
+                }
 
-        if ast.error.is_some() {
-            return result.failure(ast.error);
-        }
+                let mut interpreter = Interpreter::new().called_from(self);
+                let module_context = Rc::new(RefCell::new(Context::new(
+                    "<module>".to_string(),
+                    None,
+                    None,
+                )));
+                // modules get their own table so only their symbols are handed back to the caller
+                module_context.borrow_mut().symbol_table = Some(Rc::new(RefCell::new(
+                    SymbolTable::new(Some(self.global_symbol_table.clone())),
+                )));
+                self.set_file_symbols(module_context.clone(), &file_to_import);
+                interpreter.module_cache = std::mem::take(&mut self.module_cache);
+                interpreter.import_stack = import_stack.clone();
+                let module_result =
+                    interpreter.visit(ast.node.unwrap(), module_context.clone());
+                self.module_cache = std::mem::take(&mut interpreter.module_cache);
+
+                if module_result.error.is_some() {
+                    return result.failure(Self::trace_import(module_result.error, &import_stack));
+                }
 
-        let mut interpreter = Interpreter::new();
-        let module_context = Rc::new(RefCell::new(Context::new(
-            "<module>".to_string(),
-            None,
-            None,
-        )));
-        module_context.borrow_mut().symbol_table = Some(self.global_symbol_table.clone());
-        let module_result = interpreter.visit(ast.node.unwrap(), module_context.clone());
+                if let Some(cache_key) = cache_key {
+                    self.module_cache.insert(cache_key, module_context.clone());
+                }
 
-        if module_result.error.is_some() {
-            return result.failure(module_result.error);
-        }
+                module_context
+            }
+        };
 
-        let symbols: Vec<(String, Option<Value>)> = module_context
-            .borrow()
-            .symbol_table
-            .as_ref()
-            .unwrap()
+        let module_table = module_context.borrow().symbol_table.clone().unwrap();
+        // read through 'get', so functions handed out hold on to the module's scope
+        let mut symbols: Vec<(String, Option<Value>)> = module_table
             .borrow()
             .symbols
-            .iter()
-            .map(|(k, v)| (k.clone(), v.clone()))
+            .keys()
+            .filter(|k| !FILE_SYMBOLS.contains(&k.as_str()))
+            .map(|k| (k.clone(), module_table.borrow().get(k)))
             .collect();
 
-        for (name, value) in symbols {
+        // the table is a HashMap, sorting by name binds the symbols in the same order every run
+        symbols.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        // a module that exports anything keeps the rest of its symbols private
+        let exports = module_context.borrow().exports.clone();
+        let symbols: Vec<(String, Option<Value>)> = if exports.is_empty() {
+            symbols
+        } else {
+            symbols
+                .into_iter()
+                .filter(|(name, _)| exports.contains(name))
+                .collect()
+        };
+
+        if let Some(alias_token) = &node.alias_token {
+            // whatever the module fetched itself stays out of its namespace
+            let imports = module_context.borrow().imports.clone();
+            let entries: HashMap<String, Value> = symbols
+                .into_iter()
+                .filter(|(name, _)| !imports.contains(name) || exports.contains(name))
+                .filter_map(|(name, value)| Some((name, value?)))
+                .collect();
+            let namespace = Map::from(entries)
+                .set_context(Some(context.clone()))
+                .set_position(node.pos_start.clone(), node.pos_end.clone());
+
             context
                 .borrow_mut()
                 .symbol_table
                 .as_ref()
                 .unwrap()
                 .borrow_mut()
-                .set(name, value);
+                .set(alias_token.value.as_ref().unwrap().clone(), Some(namespace));
+
+            return result.success(Some(Value::NullValue(Null::new())));
+        }
+
+        let constants = module_context
+            .borrow()
+            .symbol_table
+            .as_ref()
+            .unwrap()
+            .borrow()
+            .constants
+            .clone();
+
+        for (name, value) in symbols {
+            let mut context = context.borrow_mut();
+
+            if !context.imports.contains(&name) {
+                context.imports.push(name.clone());
+            }
+
+            let mut symbol_table = context.symbol_table.as_ref().unwrap().borrow_mut();
+
+            if constants.contains(&name) {
+                symbol_table.set_constant(name, value);
+            } else {
+                symbol_table.set(name, value);
+            }
+        }
+
+        result.success(Some(Value::NullValue(Null::new())))
+    }
+
+    // file names along a chain of imports, for error messages
+    fn import_chain<'a>(paths: impl Iterator<Item = &'a PathBuf>) -> Vec<String> {
+        paths
+            .map(|path| match path.file_name() {
+                Some(name) => name.to_string_lossy().to_string(),
+                None => path.to_string_lossy().to_string(),
+            })
+            .collect()
+    }
+
+    // an error from inside a module remembers the imports that led to it, the innermost
+    // import fills it in so outer ones leave it alone
+    fn trace_import(
+        error: Option<StandardError>,
+        import_stack: &[PathBuf],
+    ) -> Option<StandardError> {
+        error.map(|mut error| {
+            if error.traceback.import_chain.is_empty() {
+                error.traceback.import_chain = Self::import_chain(import_stack.iter());
+            }
+
+            error
+        })
+    }
+
+    // '__file__' and '__dir__' describe the script currently running in a context
+    pub fn set_file_symbols(&self, context: Rc<RefCell<Context>>, filename: &str) {
+        let dir = match Path::new(filename).parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_string_lossy().to_string(),
+            _ => ".".to_string(),
+        };
+        let context = context.borrow();
+        let mut symbol_table = context.symbol_table.as_ref().unwrap().borrow_mut();
+
+        symbol_table.set(FILE_SYMBOLS[0].to_string(), Some(Str::from(filename)));
+        symbol_table.set(FILE_SYMBOLS[1].to_string(), Some(Str::from(&dir)));
+    }
+
+    // relative imports are looked up next to the importing file first,
+    // then from the working directory
+    pub fn resolve_import_path(&self, path: &str, importer: &str) -> String {
+        let importer = Path::new(importer);
+
+        if Path::new(path).is_relative() && importer.is_file() {
+            if let Some(dir) = importer.parent() {
+                let candidate = dir.join(path);
+
+                if candidate.is_file() {
+                    return candidate.to_string_lossy().to_string();
+                }
+            }
+        }
+
+        path.to_string()
+    }
+
+    pub fn visit_export_node(
+        &mut self,
+        node: &ExportNode,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let value = result.register(self.visit(node.node_to_export.clone(), context.clone()));
+
+        if result.should_return() {
+            return result;
         }
 
-        result.success(Some(Number::null_value()))
+        context.borrow_mut().exports.push(node.export_name.clone());
+
+        result.success(value)
     }
 
     pub fn visit_function_definition_node(
@@ -677,17 +1245,23 @@ impl Interpreter {
         };
         let body_node = node.body_node.clone();
         let mut arg_names: Vec<String> = Vec::new();
+        let mut arg_defaults: Vec<Option<Box<AstNode>>> = Vec::new();
 
-        for arg_name in node.arg_name_tokens.iter() {
-            arg_names.push(arg_name.value.as_ref().unwrap().clone());
+        for arg in node.arg_name_tokens.iter() {
+            arg_names.push(arg.name_token.value.as_ref().unwrap().clone());
+            arg_defaults.push(arg.default_node.clone());
         }
 
         let func_value = Value::FunctionValue(Function::new(
             func_name.clone(),
             body_node,
             &arg_names,
+            &arg_defaults,
+            node.is_variadic,
             node.should_auto_return,
-        ))
+            context.borrow().symbol_table.clone(),
+        )
+        .with_globals(self.global_symbol_table.clone()))
         .set_context(Some(context.clone()))
         .set_position(node.pos_start.clone(), node.pos_end.clone());
 
@@ -723,7 +1297,7 @@ impl Interpreter {
             .set_position(node.pos_start.clone(), node.pos_end.clone());
 
         for arg_node in &node.arg_nodes {
-            let arg = result.register(self.visit(arg_node.to_owned(), context.clone()));
+            let arg = result.register(self.visit(Box::new(arg_node.to_owned()), context.clone()));
 
             if result.should_return() {
                 return result;
@@ -734,9 +1308,34 @@ impl Interpreter {
             args.push(arg);
         }
 
+        // lists are values, so 'pop' on a variable writes the shortened list back to it
+        let popped_variable = match (&value_to_call, node.arg_nodes.first()) {
+            (Value::BuiltInFunction(function), Some(AstNode::VariableAccess(access)))
+                if function.name == "pop" =>
+            {
+                access.var_name_token.value.clone()
+            }
+            _ => None,
+        };
+
+        if let Some(var_name) = &popped_variable {
+            if context.borrow().symbol_table.as_ref().unwrap().borrow().is_constant(var_name) {
+                return result.failure(Some(StandardError::new(
+                    "cannot reassign the value of a constant",
+                    node.pos_start.as_ref().unwrap().to_owned(),
+                    node.pos_end.as_ref().unwrap().to_owned(),
+                    Some(
+                        format!("'{var_name}' was defined with 'stay', use 'obj' to allow changes")
+                            .as_str(),
+                    ),
+                )));
+            }
+        }
+
         let return_value = result.register(match value_to_call {
-            Value::FunctionValue(value) => value.execute(&args),
-            Value::BuiltInFunction(value) => value.execute(&args),
+            Value::FunctionValue(value) => value.execute(&args, self),
+            Value::BuiltInFunction(value) => value.execute(&args, self),
+            Value::StructConstructor(value) => value.execute(&args),
             _ => {
                 return result.failure(Some(StandardError::new(
                     "expected function as call",
@@ -751,6 +1350,14 @@ impl Interpreter {
             return result;
         }
 
+        if let (Some(var_name), Some(Value::ListValue(list))) = (popped_variable, args.first()) {
+            let mut rest = list.clone();
+            rest.elements.pop();
+
+            let symbol_table = context.borrow().symbol_table.clone().unwrap();
+            symbol_table.borrow_mut().set(var_name, Some(Value::ListValue(rest)));
+        }
+
         let return_value = return_value
             .unwrap()
             .set_position(node.pos_start.clone(), node.pos_end.clone())
@@ -759,73 +1366,365 @@ impl Interpreter {
         result.success(Some(return_value))
     }
 
-    pub fn visit_binary_operator_node(
+    pub fn visit_method_call_node(
         &mut self,
-        node: &BinaryOperatorNode,
+        node: &MethodCallNode,
         context: Rc<RefCell<Context>>,
     ) -> RuntimeResult {
         let mut result = RuntimeResult::new();
-        let left = result.register(self.visit(node.left_node.clone(), context.clone()));
+
+        let receiver = result.register(self.visit(node.receiver_node.clone(), context.clone()));
 
         if result.should_return() {
             return result;
         }
 
-        let mut left = left.unwrap();
+        let method_name = result.register(self.visit(node.method_node.clone(), context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let mut receiver = receiver.unwrap();
+        let method = match receiver.perform_operation("^", method_name.unwrap()) {
+            Ok(mut method) => method.set_position(node.pos_start.clone(), node.pos_end.clone()),
+            Err(e) => return result.failure(Some(e)),
+        };
+
+        // the receiver is always the first argument, like 'self'
+        let mut args: Vec<Value> = vec![receiver];
 
-        let right = result.register(self.visit(node.right_node.clone(), context.clone()));
+        for arg_node in &node.arg_nodes {
+            let arg = result.register(self.visit(Box::new(arg_node.to_owned()), context.clone()));
+
+            if result.should_return() {
+                return result;
+            }
+
+            args.push(arg.unwrap());
+        }
+
+        let return_value = result.register(match method {
+            Value::FunctionValue(value) => value.execute(&args, self),
+            Value::BuiltInFunction(value) => value.execute(&args, self),
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected function as method",
+                    node.pos_start.as_ref().unwrap().clone(),
+                    node.pos_end.as_ref().unwrap().clone(),
+                    None,
+                )));
+            }
+        });
 
         if result.should_return() {
             return result;
         }
 
-        let right = right.unwrap();
+        let return_value = return_value
+            .unwrap()
+            .set_position(node.pos_start.clone(), node.pos_end.clone())
+            .set_context(Some(context.clone()));
 
-        let operation_result: Result<Value, StandardError>;
+        result.success(Some(return_value))
+    }
+
+    pub fn visit_property_access_node(
+        &mut self,
+        node: &PropertyAccessNode,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+
+        let object = result.register(self.visit(node.object.clone(), context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
 
+        let (value, message, help) = match object.unwrap() {
+            Value::MapValue(map) => (
+                map.entries.get(&node.property).cloned(),
+                format!("key '{}' doesn't exist", node.property),
+                map.key_help(),
+            ),
+            Value::StructInstance(instance) => (
+                instance.fields.get(&node.property).cloned(),
+                instance.missing_field(&node.property),
+                instance.field_help(),
+            ),
+            object => {
+                return result.failure(Some(StandardError::new(
+                    format!("cannot read '{}' from a {}", node.property, object.object_type())
+                        .as_str(),
+                    node.pos_start.as_ref().unwrap().clone(),
+                    node.pos_end.as_ref().unwrap().clone(),
+                    Some("only maps and structs have properties you can read with '.'"),
+                )));
+            }
+        };
+
+        match value {
+            Some(mut value) => result.success(Some(
+                value
+                    .set_position(node.pos_start.clone(), node.pos_end.clone())
+                    .set_context(Some(context.clone())),
+            )),
+            None => result.failure(Some(StandardError::new(
+                message.as_str(),
+                node.pos_start.as_ref().unwrap().clone(),
+                node.pos_end.as_ref().unwrap().clone(),
+                Some(help.as_str()),
+            ))),
+        }
+    }
+
+    pub fn visit_property_assign_node(
+        &mut self,
+        node: &PropertyAssignNode,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let var_name = node.var_name_token.value.as_ref().unwrap().clone();
+        let property = node.property_token.value.as_ref().unwrap().clone();
+
+        let current = result.register(self.visit_variable_access_node(
+            &VariableAccessNode::new(node.var_name_token.clone()),
+            context.clone(),
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let symbol_table = context.borrow().symbol_table.clone().unwrap();
+
+        if symbol_table.borrow().is_constant(&var_name) {
+            return result.failure(Some(StandardError::new(
+                "cannot reassign the value of a constant",
+                node.pos_start.as_ref().unwrap().to_owned(),
+                node.property_token.pos_end.as_ref().unwrap().to_owned(),
+                Some(
+                    format!("'{var_name}' was defined with 'stay', use 'obj' to allow changes")
+                        .as_str(),
+                ),
+            )));
+        }
+
+        let value = result.register(self.visit(node.value_node.clone(), context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let value = value.unwrap();
+
+        let updated = match current.unwrap() {
+            Value::MapValue(mut map) => {
+                map.entries.insert(property, value.clone());
+                Value::MapValue(map)
+            }
+            // a struct's fields are fixed by its definition, only existing ones can change
+            Value::StructInstance(mut instance) => {
+                if !instance.fields.contains_key(&property) {
+                    return result.failure(Some(StandardError::new(
+                        instance.missing_field(&property).as_str(),
+                        node.pos_start.as_ref().unwrap().to_owned(),
+                        node.property_token.pos_end.as_ref().unwrap().to_owned(),
+                        Some(instance.field_help().as_str()),
+                    )));
+                }
+
+                instance.fields.insert(property, value.clone());
+                Value::StructInstance(instance)
+            }
+            current => {
+                return result.failure(Some(StandardError::new(
+                    format!("cannot set '{property}' on a {}", current.object_type()).as_str(),
+                    node.pos_start.as_ref().unwrap().to_owned(),
+                    node.property_token.pos_end.as_ref().unwrap().to_owned(),
+                    Some("only maps and structs have properties you can set with '.'"),
+                )));
+            }
+        };
+
+        symbol_table.borrow_mut().set(var_name, Some(updated));
+
+        result.success(Some(value))
+    }
+
+    pub fn visit_struct_def_node(
+        &mut self,
+        node: &StructDefNode,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let name = node.name_token.value.as_ref().unwrap().clone();
+        let field_names: Vec<String> = node
+            .field_name_tokens
+            .iter()
+            .map(|token| token.value.as_ref().unwrap().clone())
+            .collect();
+
+        let struct_value = Value::StructConstructor(StructDef::new(name.clone(), &field_names))
+            .set_position(node.pos_start.clone(), node.pos_end.clone())
+            .set_context(Some(context.clone()));
+
+        context
+            .borrow_mut()
+            .symbol_table
+            .as_mut()
+            .unwrap()
+            .borrow_mut()
+            .set(name, Some(struct_value.clone()));
+
+        result.success(Some(struct_value))
+    }
+
+    // a chain like 1 + 2 + 3 is a tree leaning left, one operator node on top of the next. it's
+    // worked through from the innermost operator out rather than by visiting each in turn, so
+    // a long flat chain doesn't count as nesting
+    pub fn visit_binary_operator_node(
+        &mut self,
+        node: &BinaryOperatorNode,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let mut chain = vec![node];
+
+        while let AstNode::BinaryOperator(inner) = chain.last().unwrap().left_node.as_ref() {
+            chain.push(inner);
+        }
+
+        let left = result.register(
+            self.visit(chain.last().unwrap().left_node.clone(), context.clone()),
+        );
+
+        if result.should_return() {
+            return result;
+        }
+
+        let mut left = left.unwrap();
+
+        for (index, node) in chain.into_iter().enumerate().rev() {
+            // the outermost operator was counted when it was visited, the rest are counted here
+            if index > 0 {
+                if let Some((text, help)) = self.exceeded_limit() {
+                    return result.failure(Some(StandardError::new(
+                        text,
+                        node.pos_start.clone().unwrap(),
+                        node.pos_end.clone().unwrap(),
+                        Some(help),
+                    )));
+                }
+            }
+
+            // '??' only looks at its right side when the left one is null
+            if node.op_token.token_type == TokenType::TT_COALESCE
+                && !matches!(left, Value::NullValue(_))
+            {
+                continue;
+            }
+
+            let right = result.register(self.visit(node.right_node.clone(), context.clone()));
+
+            if result.should_return() {
+                return result;
+            }
+
+            left = match self.operate(node, left, right.unwrap()) {
+                Ok(mut value) => value.set_position(node.pos_start.clone(), node.pos_end.clone()),
+                Err(error) => return result.failure(Some(error)),
+            };
+        }
+
+        result.success(Some(left))
+    }
+
+    fn operate(
+        &self,
+        node: &BinaryOperatorNode,
+        left: Value,
+        right: Value,
+    ) -> Result<Value, StandardError> {
         if node.op_token.token_type == TokenType::TT_PLUS {
-            operation_result = left.perform_operation("+", right);
+            self.perform_operation(left, "+", right)
         } else if node.op_token.token_type == TokenType::TT_MINUS {
-            operation_result = left.perform_operation("-", right);
+            self.perform_operation(left, "-", right)
         } else if node.op_token.token_type == TokenType::TT_MUL {
-            operation_result = left.perform_operation("*", right);
+            self.perform_operation(left, "*", right)
         } else if node.op_token.token_type == TokenType::TT_DIV {
-            operation_result = left.perform_operation("/", right);
+            self.perform_operation(left, "/", right)
         } else if node.op_token.token_type == TokenType::TT_POW {
-            operation_result = left.perform_operation("^", right);
+            self.perform_operation(left, "^", right)
         } else if node.op_token.token_type == TokenType::TT_MOD {
-            operation_result = left.perform_operation("%", right);
+            self.perform_operation(left, "%", right)
         } else if node.op_token.token_type == TokenType::TT_GT {
-            operation_result = left.perform_operation(">", right);
+            self.perform_operation(left, ">", right)
         } else if node.op_token.token_type == TokenType::TT_LT {
-            operation_result = left.perform_operation("<", right);
+            self.perform_operation(left, "<", right)
         } else if node.op_token.token_type == TokenType::TT_EE {
-            operation_result = left.perform_operation("==", right);
+            self.perform_operation(left, "==", right)
         } else if node.op_token.token_type == TokenType::TT_NE {
-            operation_result = left.perform_operation("!=", right);
+            self.perform_operation(left, "!=", right)
         } else if node.op_token.token_type == TokenType::TT_LTE {
-            operation_result = left.perform_operation("<=", right);
+            self.perform_operation(left, "<=", right)
         } else if node.op_token.token_type == TokenType::TT_GTE {
-            operation_result = left.perform_operation(">=", right);
+            self.perform_operation(left, ">=", right)
         } else if node.op_token.matches(TokenType::TT_KEYWORD, "and") {
-            operation_result = left.perform_operation("and", right);
+            self.perform_operation(left, "and", right)
         } else if node.op_token.matches(TokenType::TT_KEYWORD, "or") {
-            operation_result = left.perform_operation("or", right);
+            self.perform_operation(left, "or", right)
+        } else if node.op_token.token_type == TokenType::TT_COALESCE {
+            Ok(right)
+        } else if node.op_token.matches(TokenType::TT_KEYWORD, "in") {
+            right.contains(&left).map(Bool::from)
+        } else if node.op_token.matches(TokenType::TT_KEYWORD, "not in") {
+            right.contains(&left).map(|found| Bool::from(!found))
         } else {
-            operation_result = left.perform_operation("", right);
+            self.perform_operation(left, "", right)
         }
+    }
 
-        if operation_result.is_err() {
-            result.failure(operation_result.err())
-        } else if operation_result.is_ok() {
-            return result.success(Some(
-                operation_result
-                    .ok()
-                    .unwrap()
-                    .set_position(node.pos_start.clone(), node.pos_end.clone()),
-            ));
-        } else {
-            return result.success(Some(Number::null_value()));
+    // runs an operator on two values, holding the lists it builds to the run's limits
+    fn perform_operation(
+        &self,
+        mut left: Value,
+        operator: &str,
+        right: Value,
+    ) -> Result<Value, StandardError> {
+        self.check_growth(&left, operator, &right)?;
+
+        left.perform_operation(operator, right)
+    }
+
+    // lists are copied on every change, so unbounded growth in a loop eats memory fast. a
+    // repeat count the string refuses anyway is left for it to report
+    fn check_growth(&self, left: &Value, operator: &str, right: &Value) -> Result<(), StandardError> {
+        match (left, operator, right) {
+            (Value::ListValue(list), "*", _) => self.limits.check_list_length(
+                list.elements.len() + 1,
+                left.position_start().unwrap(),
+                right.position_end().unwrap(),
+            ),
+            (Value::ListValue(list), "+", Value::ListValue(other)) => {
+                self.limits.check_list_length(
+                    list.elements.len() + other.elements.len(),
+                    left.position_start().unwrap(),
+                    right.position_end().unwrap(),
+                )
+            }
+            (Value::StringValue(text), "*", Value::NumberValue(count))
+                if count.value >= 0.0 && count.value.is_finite() && count.value.fract() == 0.0 =>
+            {
+                self.limits.check_repeat_length(
+                    text.value.len(),
+                    count.value,
+                    right.position_start().unwrap(),
+                    right.position_end().unwrap(),
+                )
+            }
+            _ => Ok(()),
         }
     }
 
@@ -848,7 +1747,7 @@ impl Interpreter {
         if node.op_token.token_type == TokenType::TT_MINUS {
             operation_result = value.perform_operation("*", Number::from(-1.0));
         } else if node.op_token.matches(TokenType::TT_KEYWORD, "not") {
-            operation_result = value.perform_operation("not", Number::false_value());
+            operation_result = value.perform_operation("not", Bool::from(false));
         } else {
             operation_result = Err(StandardError::new(
                 "unsupported unary operation",
@@ -868,7 +1767,7 @@ impl Interpreter {
                     .set_position(node.pos_start.clone(), node.pos_end.clone()),
             ));
         } else {
-            return result.success(Some(Number::null_value()));
+            return result.success(Some(Value::NullValue(Null::new())));
         }
     }
 
@@ -888,7 +1787,7 @@ impl Interpreter {
                 return result;
             }
         } else {
-            value = Some(Number::null_value())
+            value = Some(Value::NullValue(Null::new()))
         }
 
         let value = value.unwrap();
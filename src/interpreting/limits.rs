@@ -0,0 +1,70 @@
+use crate::{
+    errors::standard_error::StandardError, lexing::position::Position,
+    syntax::attributes::DEFAULT_MAX_REPEAT_LENGTH,
+};
+use std::time::Duration;
+
+// bounds on how much a run may do before it's halted with an error, for untrusted scripts.
+// loading the default library doesn't count towards either of them
+#[derive(Clone, Copy)]
+pub struct Limits {
+    // most nodes the program may visit
+    pub max_operations: Option<usize>,
+    // longest the program may run for
+    pub timeout: Option<Duration>,
+    // most elements any list may grow to
+    pub max_list_length: Option<usize>,
+    // longest string (in bytes) multiplying a string may build
+    pub max_repeat_length: Option<usize>,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_operations: None,
+            timeout: None,
+            max_list_length: None,
+            max_repeat_length: Some(DEFAULT_MAX_REPEAT_LENGTH),
+        }
+    }
+}
+
+impl Limits {
+    // for anything building a new list of 'length' elements, from a literal to a built-in
+    pub fn check_list_length(
+        &self,
+        length: usize,
+        pos_start: Position,
+        pos_end: Position,
+    ) -> Result<(), StandardError> {
+        match self.max_list_length {
+            Some(limit) if length > limit => Err(StandardError::new(
+                format!("list would grow past its limit of {limit} elements").as_str(),
+                pos_start,
+                pos_end,
+                Some("the limit is set by whoever is running the script"),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    // for a string of 'length' bytes repeated 'count' times. checked in f64, huge counts don't
+    // fit in a usize
+    pub fn check_repeat_length(
+        &self,
+        length: usize,
+        count: f64,
+        pos_start: Position,
+        pos_end: Position,
+    ) -> Result<(), StandardError> {
+        match self.max_repeat_length {
+            Some(limit) if count * length as f64 > limit as f64 => Err(StandardError::new(
+                "repeated string would be too long",
+                pos_start,
+                pos_end,
+                Some(format!("strings can be repeated up to {limit} bytes").as_str()),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 pub mod context;
 pub mod interpreter;
+pub mod limits;
 pub mod runtime_result;
 pub mod symbol_table;
@@ -1,9 +1,14 @@
 use crate::values::value::Value;
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
 
 #[derive(Debug, Clone)]
 pub struct SymbolTable {
     pub symbols: HashMap<String, Option<Value>>,
+    pub constants: HashSet<String>,
     pub parent: Option<Rc<RefCell<SymbolTable>>>,
 }
 
@@ -11,13 +16,19 @@ impl SymbolTable {
     pub fn new(parent: Option<Rc<RefCell<SymbolTable>>>) -> Self {
         Self {
             symbols: HashMap::new(),
+            constants: HashSet::new(),
             parent,
         }
     }
 
     pub fn get(&self, name: &str) -> Option<Value> {
         if let Some(value) = self.symbols.get(name) {
-            return value.clone();
+            return match value.clone() {
+                Some(Value::FunctionValue(function)) => {
+                    Some(Value::FunctionValue(function.taken_out()))
+                }
+                value => value,
+            };
         }
 
         if let Some(parent) = &self.parent {
@@ -32,9 +43,33 @@ impl SymbolTable {
             return;
         }
 
+        let value = match value {
+            Some(Value::FunctionValue(function)) => {
+                Some(Value::FunctionValue(function.stored_in(self)))
+            }
+            value => value,
+        };
+
         self.symbols.insert(name, value);
     }
 
+    pub fn set_constant(&mut self, name: String, value: Option<Value>) {
+        self.constants.insert(name.clone());
+        self.set(name, value);
+    }
+
+    // whether the name was defined with 'stay' here or in any parent table
+    pub fn is_constant(&self, name: &str) -> bool {
+        if self.constants.contains(name) {
+            return true;
+        }
+
+        match &self.parent {
+            Some(parent) => parent.borrow().is_constant(name),
+            None => false,
+        }
+    }
+
     pub fn remove(&mut self, name: &str) {
         self.symbols.remove(name);
     }
@@ -0,0 +1,18 @@
+// a comment the lexer kept, as written with its '#' marks, for tools that print code back out
+#[derive(Debug, Clone)]
+pub struct Comment {
+    pub text: String,
+    pub line_num: isize,
+    // written at the end of a line of code rather than on a line of its own
+    pub after_code: bool,
+}
+
+impl Comment {
+    pub fn new(text: String, line_num: isize, after_code: bool) -> Self {
+        Self {
+            text,
+            line_num,
+            after_code,
+        }
+    }
+}
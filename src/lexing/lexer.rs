@@ -1,4 +1,5 @@
 use crate::errors::standard_error::StandardError;
+use crate::lexing::comment::Comment;
 use crate::lexing::position::Position;
 use crate::lexing::token::Token;
 use crate::lexing::token_type::TokenType;
@@ -12,6 +13,12 @@ pub struct Lexer {
     pub chars: Arc<[char]>,
     pub position: Position,
     pub current_char: Option<char>,
+    pub keep_comments: bool,
+    // every comment read, when keeping them
+    pub comments: Vec<Comment>,
+    // lines of the comment being read, and the line it ends on
+    comment_lines: Vec<String>,
+    comment_end_line: isize,
 }
 
 impl Lexer {
@@ -22,14 +29,26 @@ impl Lexer {
             filename: filename.to_string(),
             text: contents.to_string(),
             chars: contents.chars().collect::<Vec<_>>().into(),
-            position: Position::new(-1, 0, -1, filename, &contents.clone()),
+            position: Position::new(-1, 0, -1, filename, Arc::from(contents.as_str())),
             current_char: None,
+            keep_comments: false,
+            comments: Vec::new(),
+            comment_lines: Vec::new(),
+            comment_end_line: -1,
         };
         lexer.advance();
 
         lexer
     }
 
+    // holds on to comments rather than throwing them away, and attaches the '#' lines right
+    // above a token to it as its doc
+    pub fn keeping_comments(mut self) -> Self {
+        self.keep_comments = true;
+
+        self
+    }
+
     pub fn advance(&mut self) {
         self.position.advance(self.current_char);
 
@@ -50,21 +69,32 @@ impl Lexer {
 
                     continue;
                 }
-                '#' => {
-                    self.skip_comment();
+                '#' if self.chars.get(self.position.index as usize + 1) == Some(&'#') => {
+                    let pos_start = self.position.clone();
+
+                    self.skip_block_comment()?;
+                    self.keep_comment(&tokens, &pos_start);
 
                     continue;
                 }
-                ';' | '\n' => {
-                    let token = Token::new(
-                        TokenType::TT_NEWLINE,
-                        None,
-                        Some(self.position.clone()),
-                        None,
-                    );
-                    self.advance();
+                '#' => {
+                    let pos_start = self.position.clone();
+                    let line_num = pos_start.line_num;
+                    let text = self.skip_comment();
 
-                    Some(token)
+                    // comments after code on the same line don't document what comes next
+                    if self.keep_comments && !Self::follows_code(&tokens, line_num) {
+                        if self.comment_end_line != line_num - 1 {
+                            self.comment_lines.clear();
+                        }
+
+                        self.comment_lines.push(text.trim().to_string());
+                        self.comment_end_line = line_num;
+                    }
+
+                    self.keep_comment(&tokens, &pos_start);
+
+                    continue;
                 }
                 c if DIGITS.contains(c) => match self.make_number() {
                     Ok(token) => Some(token),
@@ -75,47 +105,12 @@ impl Lexer {
                     Ok(token) => Some(token),
                     Err(error) => return Err(error),
                 },
-                '+' => {
-                    let token =
-                        Token::new(TokenType::TT_PLUS, None, Some(self.position.clone()), None);
-
-                    self.advance();
-
-                    Some(token)
-                }
+                '+' => Some(self.make_operator(TokenType::TT_PLUS, TokenType::TT_PLUS_EQ)),
                 '-' => Some(self.make_minus_or_arrow()),
-                '*' => {
-                    let token =
-                        Token::new(TokenType::TT_MUL, None, Some(self.position.clone()), None);
-
-                    self.advance();
-
-                    Some(token)
-                }
-                '/' => {
-                    let token =
-                        Token::new(TokenType::TT_DIV, None, Some(self.position.clone()), None);
-
-                    self.advance();
-
-                    Some(token)
-                }
-                '^' => {
-                    let token =
-                        Token::new(TokenType::TT_POW, None, Some(self.position.clone()), None);
-
-                    self.advance();
-
-                    Some(token)
-                }
-                '%' => {
-                    let token =
-                        Token::new(TokenType::TT_MOD, None, Some(self.position.clone()), None);
-
-                    self.advance();
-
-                    Some(token)
-                }
+                '*' => Some(self.make_operator(TokenType::TT_MUL, TokenType::TT_MUL_EQ)),
+                '/' => Some(self.make_operator(TokenType::TT_DIV, TokenType::TT_DIV_EQ)),
+                '^' => Some(self.make_operator(TokenType::TT_POW, TokenType::TT_POW_EQ)),
+                '%' => Some(self.make_operator(TokenType::TT_MOD, TokenType::TT_MOD_EQ)),
                 '(' => {
                     let token = Token::new(
                         TokenType::TT_LPAREN,
@@ -176,28 +171,42 @@ impl Lexer {
 
                     Some(token)
                 }
+                ':' => {
+                    let token = Token::new(
+                        TokenType::TT_COLON,
+                        None,
+                        Some(self.position.clone()),
+                        None,
+                    );
 
+                    self.advance();
+
+                    Some(token)
+                }
                 ';' => {
-                   let tok = Token::new(
-                       TokenType::TT_SEMI,
-                       None,
-                       Some(self.position.clone()),
-                       Some(self.position.clone()),
-                       );
-                       self.advance();
-                       Some(tok)
+                    let token = Token::new(
+                        TokenType::TT_SEMI,
+                        None,
+                        Some(self.position.clone()),
+                        None,
+                    );
+
+                    self.advance();
+
+                    Some(token)
                 }
                 '\n' => {
-                   let tok = Token::new(
-                       TokenType::TT_NEWLINE,
-                       None,
-                       Some(self.position.clone()),
-                       Some(self.position.clone()),
-                       );
-                       self.advance();
-                       Some(tok)
-                   }
+                    let token = Token::new(
+                        TokenType::TT_NEWLINE,
+                        None,
+                        Some(self.position.clone()),
+                        None,
+                    );
 
+                    self.advance();
+
+                    Some(token)
+                }
                 '}' => {
                     let token = Token::new(
                         TokenType::TT_RBRACKET,
@@ -214,7 +223,22 @@ impl Lexer {
                     Ok(token) => Some(token),
                     Err(error) => return Err(error),
                 },
+                '?' => match self.make_coalesce() {
+                    Ok(token) => Some(token),
+                    Err(error) => return Err(error),
+                },
                 '=' => Some(self.make_equals()),
+                '.' if self.chars.get(self.position.index as usize + 1) != Some(&'.') => {
+                    let token =
+                        Token::new(TokenType::TT_DOT, None, Some(self.position.clone()), None);
+                    self.advance();
+
+                    Some(token)
+                }
+                '.' => match self.make_ellipsis() {
+                    Ok(token) => Some(token),
+                    Err(error) => return Err(error),
+                },
                 '<' => Some(self.make_less_than()),
                 '>' => Some(self.make_greater_than()),
                 ',' => {
@@ -237,7 +261,17 @@ impl Lexer {
                 }
             };
 
-            if let Some(t) = token {
+            if let Some(mut t) = token {
+                if t.token_type != TokenType::TT_NEWLINE && !self.comment_lines.is_empty() {
+                    let line_num = t.pos_start.as_ref().map_or(-1, |pos| pos.line_num);
+
+                    if line_num == self.comment_end_line + 1 {
+                        t.doc = Some(self.comment_lines.join("\n").into());
+                    }
+
+                    self.comment_lines.clear();
+                }
+
                 tokens.push(t);
             }
         }
@@ -253,13 +287,34 @@ impl Lexer {
     }
 
     pub fn make_number(&mut self) -> Result<Token, StandardError> {
+        if self.current_char == Some('0') {
+            let radix = match self.chars.get(self.position.index as usize + 1) {
+                Some('x') => Some((16, "0-9 and a-f")),
+                Some('o') => Some((8, "0-7")),
+                Some('b') => Some((2, "0 and 1")),
+                _ => None,
+            };
+
+            if let Some((radix, digits)) = radix {
+                return self.make_radix_number(radix, digits);
+            }
+        }
+
         let mut num_str = String::new();
         let mut dot_count = 0;
+        let mut has_exponent = false;
         let pos_start = self.position.clone();
 
         while let Some(character) = self.current_char {
             if character.is_ascii_digit() {
                 num_str.push(character);
+            } else if character == '_' {
+                self.check_digit_separator(&num_str, 10, &pos_start)?;
+            } else if matches!(character, 'e' | 'E') {
+                self.make_exponent(&mut num_str, &pos_start)?;
+                has_exponent = true;
+
+                break;
             } else if character == '.' {
                 if dot_count == 1 {
                     break;
@@ -280,12 +335,24 @@ impl Lexer {
             self.advance();
         }
 
-        let token_type = if dot_count == 0 {
+        let token_type = if dot_count == 0 && !has_exponent {
             TokenType::TT_INT
         } else {
             TokenType::TT_FLOAT
         };
 
+        // numbers are stored as floats, so big integer literals would silently change value
+        if token_type == TokenType::TT_INT
+            && num_str.parse::<u64>().map_or(true, |n| n > MAX_EXACT_INTEGER)
+        {
+            return Err(StandardError::new(
+                "integer literal is too large to be represented exactly",
+                pos_start,
+                self.position.clone(),
+                Some("integers above 9007199254740992 lose precision, add a '.0' to use an approximate float instead"),
+            ));
+        }
+
         Ok(Token::new(
             token_type,
             Some(num_str),
@@ -294,6 +361,126 @@ impl Lexer {
         ))
     }
 
+    // '0xff', '0o77' and '0b1010' literals, handed on as their decimal value so the parser
+    // and interpreter treat them like any other integer
+    pub fn make_radix_number(&mut self, radix: u32, digits: &str) -> Result<Token, StandardError> {
+        let pos_start = self.position.clone();
+        let mut num_str = String::new();
+
+        // the '0' and the base letter
+        self.advance();
+        self.advance();
+
+        while let Some(character) =
+            self.current_char.filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+        {
+            if character == '_' {
+                self.check_digit_separator(&num_str, radix, &pos_start)?;
+                self.advance();
+
+                continue;
+            }
+
+            if !character.is_digit(radix) {
+                self.advance();
+
+                return Err(StandardError::new(
+                    format!("invalid digit '{character}' for a base {radix} number").as_str(),
+                    pos_start,
+                    self.position.clone(),
+                    Some(format!("the digits of a base {radix} number are {digits}").as_str()),
+                ));
+            }
+
+            num_str.push(character);
+            self.advance();
+        }
+
+        if num_str.is_empty() {
+            return Err(StandardError::new(
+                format!("expected digits for a base {radix} number").as_str(),
+                pos_start,
+                self.position.clone(),
+                Some(format!("the digits of a base {radix} number are {digits}").as_str()),
+            ));
+        }
+
+        match u64::from_str_radix(&num_str, radix) {
+            Ok(value) if value <= MAX_EXACT_INTEGER => Ok(Token::new(
+                TokenType::TT_INT,
+                Some(value.to_string()),
+                Some(pos_start),
+                Some(self.position.clone()),
+            )),
+            _ => Err(StandardError::new(
+                "integer literal is too large to be represented exactly",
+                pos_start,
+                self.position.clone(),
+                Some("integers above 9007199254740992 lose precision"),
+            )),
+        }
+    }
+
+    // '_' groups digits like '1_000_000', it's dropped from the value but has to sit between
+    // two digits, so not at either end, next to the '.' or next to another '_'
+    fn check_digit_separator(
+        &self,
+        num_str: &str,
+        radix: u32,
+        pos_start: &Position,
+    ) -> Result<(), StandardError> {
+        let before = num_str.chars().last();
+        let after = self.chars.get(self.position.index as usize + 1);
+
+        if before.is_some_and(|c| c.is_digit(radix)) && after.is_some_and(|c| c.is_digit(radix)) {
+            return Ok(());
+        }
+
+        let mut pos_end = self.position.clone();
+        pos_end.advance(self.current_char);
+
+        Err(StandardError::new(
+            "misplaced '_' in a number",
+            pos_start.clone(),
+            pos_end,
+            Some("an '_' can only separate two digits, like '1_000'"),
+        ))
+    }
+
+    // scientific notation like '1e3' or '2.5e-4', an optional sign then at least one digit
+    pub fn make_exponent(
+        &mut self,
+        num_str: &mut String,
+        pos_start: &Position,
+    ) -> Result<(), StandardError> {
+        num_str.push('e');
+        self.advance();
+
+        if let Some(sign @ ('+' | '-')) = self.current_char {
+            num_str.push(sign);
+            self.advance();
+        }
+
+        let mut digit_count = 0;
+
+        while let Some(character) = self.current_char.filter(char::is_ascii_digit) {
+            num_str.push(character);
+            digit_count += 1;
+            self.advance();
+        }
+
+        if digit_count == 0 {
+            return Err(StandardError::new(
+                "malformed exponent",
+                pos_start.clone(),
+                self.position.clone(),
+                Some("add the digits of the exponent after the 'e', like '1e3'"),
+            ));
+        }
+
+        Ok(())
+    }
+
     pub fn make_identifier(&mut self) -> Token {
         let mut id_string = String::new();
         let pos_start = self.position.clone();
@@ -320,9 +507,14 @@ impl Lexer {
     }
 
     pub fn make_string(&mut self) -> Result<Token, StandardError> {
+        if self.is_triple_quote() {
+            return self.make_raw_string();
+        }
+
         let mut string = String::new();
         let pos_start = self.position.clone();
         let mut escape_char = false;
+        let mut interpolated = false;
 
         self.advance();
 
@@ -333,6 +525,7 @@ impl Lexer {
         escape_chars.insert('t', '\t');
         escape_chars.insert('\\', '\\');
         escape_chars.insert('"', '\"');
+        escape_chars.insert('}', '}');
 
         while let Some(character) = self.current_char {
             if character == '"' && !escape_char {
@@ -363,6 +556,10 @@ impl Lexer {
                             None,
                         ));
                     }
+                } else if character == '{' {
+                    // a literal brace, doubled like '{{' so it never starts an expression
+                    string.push_str("{{");
+                    self.advance();
                 } else if let Some(replacement) = escape_chars.get(&character) {
                     string.push(*replacement);
                     self.advance();
@@ -382,6 +579,16 @@ impl Lexer {
 
             if character == '\\' {
                 escape_char = true;
+            } else if character == '{' {
+                interpolated |= self.make_placeholder(&mut string)?;
+
+                continue;
+            } else if character == '}'
+                && self.chars.get(self.position.index as usize + 1) == Some(&'}')
+            {
+                // '}}' is an escaped brace, kept as a single one
+                string.push('}');
+                self.advance();
             } else {
                 string.push(character);
             }
@@ -402,14 +609,132 @@ impl Lexer {
 
         let pos_end = self.position.clone();
 
+        // the template keeps literal braces doubled, plain strings don't need that
+        let (token_type, value) = if interpolated {
+            (TokenType::TT_INTERP_STR, string)
+        } else {
+            (TokenType::TT_STR, string.replace("{{", "{"))
+        };
+
+        Ok(Token::new(
+            token_type,
+            Some(value),
+            Some(pos_start),
+            Some(pos_end),
+        ))
+    }
+
+    fn is_triple_quote(&self) -> bool {
+        let index = self.position.index as usize;
+
+        self.chars.get(index..index + 3) == Some(&['"'; 3][..])
+    }
+
+    // '"""' strings can span lines and take everything up to the closing '"""' as written,
+    // backslashes and braces included
+    pub fn make_raw_string(&mut self) -> Result<Token, StandardError> {
+        let mut string = String::new();
+        let pos_start = self.position.clone();
+
+        for _ in 0..3 {
+            self.advance();
+        }
+
+        while self.current_char.is_some() && !self.is_triple_quote() {
+            string.extend(self.current_char);
+            self.advance();
+        }
+
+        if self.current_char.is_none() {
+            return Err(StandardError::new(
+                "unfinished string",
+                pos_start,
+                self.position.clone(),
+                Some("add a '\"\"\"' at the end of the string to close it"),
+            ));
+        }
+
+        for _ in 0..3 {
+            self.advance();
+        }
+
         Ok(Token::new(
             TokenType::TT_STR,
             Some(string),
             Some(pos_start),
-            Some(pos_end),
+            Some(self.position.clone()),
         ))
     }
 
+    // reads a '{' inside a string into the template, returns whether it started an interpolated
+    // expression. '{{' is an escaped brace and an empty '{}' (like the placeholders of 'servef')
+    // is kept as it is, any other '{' needs a '}' closing it on the same line
+    fn make_placeholder(&mut self, template: &mut String) -> Result<bool, StandardError> {
+        let brace_start = self.position.clone();
+        self.advance();
+
+        if self.current_char == Some('{') {
+            template.push_str("{{");
+            self.advance();
+
+            return Ok(false);
+        }
+
+        let start = self.position.index as usize;
+        let end = self.chars[start..]
+            .iter()
+            .position(|c| matches!(c, '}' | '"' | '\n'))
+            .map(|offset| start + offset);
+
+        let expression: String = match end {
+            Some(end) if self.chars[end] == '}' => self.chars[start..end].iter().collect(),
+            _ => {
+                return Err(StandardError::new(
+                    "unmatched '{' in string",
+                    brace_start,
+                    self.position.clone(),
+                    Some("close the expression with '}', or write '\\{' for a literal brace"),
+                ));
+            }
+        };
+
+        if expression.trim().is_empty() {
+            template.push_str("{{");
+
+            return Ok(false);
+        }
+
+        template.push('{');
+        template.push_str(&expression);
+        template.push('}');
+
+        // past the expression and its closing '}'
+        for _ in 0..=expression.chars().count() {
+            self.advance();
+        }
+
+        Ok(true)
+    }
+
+    // an operator like '+', or its compound assignment form like '+=' when followed by '='
+    pub fn make_operator(&mut self, token_type: TokenType, compound_type: TokenType) -> Token {
+        let mut token_type = token_type;
+        let pos_start = self.position.clone();
+        self.advance();
+
+        if self.current_char == Some('=') {
+            self.advance();
+            token_type = compound_type;
+        }
+
+        Token::new(
+            token_type,
+            None,
+            Some(pos_start),
+            Some(self.position.clone()),
+        )
+    }
+
     pub fn make_minus_or_arrow(&mut self) -> Token {
         let mut token_type = TokenType::TT_MINUS;
         let pos_start = self.position.clone();
@@ -419,6 +744,9 @@ impl Lexer {
             if character == '>' {
                 self.advance();
                 token_type = TokenType::TT_ARROW;
+            } else if character == '=' {
+                self.advance();
+                token_type = TokenType::TT_MINUS_EQ;
             }
         }
 
@@ -477,6 +805,53 @@ impl Lexer {
         ))
     }
 
+    pub fn make_coalesce(&mut self) -> Result<Token, StandardError> {
+        let pos_start = self.position.clone();
+        self.advance();
+
+        if self.current_char == Some('?') {
+            self.advance();
+
+            return Ok(Token::new(
+                TokenType::TT_COALESCE,
+                None,
+                Some(pos_start),
+                Some(self.position.clone()),
+            ));
+        }
+
+        Err(StandardError::new(
+            "expected '?' after '?'",
+            pos_start,
+            self.position.clone(),
+            Some("use '??' to fall back to another value when one is null"),
+        ))
+    }
+
+    pub fn make_ellipsis(&mut self) -> Result<Token, StandardError> {
+        let pos_start = self.position.clone();
+
+        for _ in 0..3 {
+            if self.current_char != Some('.') {
+                return Err(StandardError::new(
+                    "expected '...'",
+                    pos_start,
+                    self.position.clone(),
+                    Some("use '...' before the last function argument to take any number of values"),
+                ));
+            }
+
+            self.advance();
+        }
+
+        Ok(Token::new(
+            TokenType::TT_ELLIPSIS,
+            None,
+            Some(pos_start),
+            Some(self.position.clone()),
+        ))
+    }
+
     pub fn make_less_than(&mut self) -> Token {
         let mut token_type = TokenType::TT_LT;
         let pos_start = self.position.clone();
@@ -517,15 +892,75 @@ impl Lexer {
         )
     }
 
-    pub fn skip_comment(&mut self) {
+    // '## ... ##' comments can span lines and sit in the middle of code, they end at the first
+    // '##' after the opening one and don't nest
+    pub fn skip_block_comment(&mut self) -> Result<(), StandardError> {
+        let pos_start = self.position.clone();
+
+        self.advance();
+        self.advance();
+
+        while let Some(character) = self.current_char {
+            self.advance();
+
+            if character == '#' && self.current_char == Some('#') {
+                self.advance();
+
+                return Ok(());
+            }
+        }
+
+        let mut pos_end = pos_start.clone();
+        pos_end.advance(Some('#'));
+        pos_end.advance(Some('#'));
+
+        Err(StandardError::new(
+            "unfinished block comment",
+            pos_start,
+            pos_end,
+            Some("add a '##' at the end of the comment to close it"),
+        ))
+    }
+
+    // whether a token was already read on the line
+    fn follows_code(tokens: &[Token], line_num: isize) -> bool {
+        tokens.last().is_some_and(|token| {
+            token.pos_start.as_ref().is_some_and(|pos| pos.line_num == line_num)
+        })
+    }
+
+    // records the comment that started at 'pos_start' and was just skipped over
+    fn keep_comment(&mut self, tokens: &[Token], pos_start: &Position) {
+        if !self.keep_comments {
+            return;
+        }
+
+        let text: String = self.chars[pos_start.index as usize..self.position.index as usize]
+            .iter()
+            .collect();
+
+        self.comments.push(Comment::new(
+            text.trim_end().to_string(),
+            pos_start.line_num,
+            Self::follows_code(tokens, pos_start.line_num),
+        ));
+    }
+
+    // gives back the text of the comment, without the '#'
+    pub fn skip_comment(&mut self) -> String {
+        let mut text = String::new();
+
         self.advance();
 
         while let Some(character) = self.current_char {
             if character != '\n' {
+                text.push(character);
                 self.advance();
             } else {
                 break;
             }
         }
+
+        text
     }
 }
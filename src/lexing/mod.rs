@@ -1,3 +1,4 @@
+pub mod comment;
 pub mod lexer;
 pub mod position;
 pub mod token;
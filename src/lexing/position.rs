@@ -1,10 +1,13 @@
+use std::sync::Arc;
+
 #[derive(Debug, Clone)]
 pub struct Position {
     pub index: isize,
     pub line_num: isize,
     pub column_num: isize,
     pub filename: String,
-    pub file_contents: String,
+    // shared by every position in a file, copying one only copies the pointer
+    pub file_contents: Arc<str>,
 }
 
 impl Position {
@@ -13,14 +16,14 @@ impl Position {
         line_num: isize,
         column_num: isize,
         filename: &str,
-        file_contents: &str,
+        file_contents: Arc<str>,
     ) -> Self {
         Self {
             index,
             line_num,
             column_num,
             filename: filename.to_string(),
-            file_contents: file_contents.to_string(),
+            file_contents,
         }
     }
 
@@ -7,6 +7,8 @@ pub struct Token {
     pub value: Option<String>,
     pub pos_start: Option<Position>,
     pub pos_end: Option<Position>,
+    // the '#' comment lines written right above the token, when the lexer keeps comments
+    pub doc: Option<Box<str>>,
 }
 
 impl Token {
@@ -31,6 +33,7 @@ impl Token {
             value,
             pos_start: start,
             pos_end: end,
+            doc: None,
         }
     }
 
@@ -7,6 +7,7 @@ pub enum TokenType {
     TT_INT,
     TT_FLOAT,
     TT_STR,
+    TT_INTERP_STR,
     TT_IDENTIFIER,
     TT_KEYWORD,
     TT_PLUS,
@@ -15,6 +16,12 @@ pub enum TokenType {
     TT_DIV,
     TT_POW,
     TT_MOD,
+    TT_PLUS_EQ,
+    TT_MINUS_EQ,
+    TT_MUL_EQ,
+    TT_DIV_EQ,
+    TT_POW_EQ,
+    TT_MOD_EQ,
     TT_EQ,
     TT_AT,
     TT_LPAREN,
@@ -30,7 +37,11 @@ pub enum TokenType {
     TT_LTE,
     TT_GTE,
     TT_COMMA,
+    TT_COLON,
     TT_ARROW,
+    TT_COALESCE,
+    TT_DOT,
+    TT_ELLIPSIS,
     TT_NEWLINE,
     TT_EOF,
     TT_SEMI,
@@ -42,6 +53,7 @@ impl Display for TokenType {
             TokenType::TT_INT => "INT",
             TokenType::TT_FLOAT => "FLOAT",
             TokenType::TT_STR => "STRING",
+            TokenType::TT_INTERP_STR => "INTERP_STRING",
             TokenType::TT_IDENTIFIER => "IDENTIFIER",
             TokenType::TT_KEYWORD => "KEYWORD",
             TokenType::TT_PLUS => "PLUS",
@@ -50,6 +62,12 @@ impl Display for TokenType {
             TokenType::TT_DIV => "DIV",
             TokenType::TT_POW => "POW",
             TokenType::TT_MOD => "MOD",
+            TokenType::TT_PLUS_EQ => "PLUS_EQ",
+            TokenType::TT_MINUS_EQ => "MINUS_EQ",
+            TokenType::TT_MUL_EQ => "MUL_EQ",
+            TokenType::TT_DIV_EQ => "DIV_EQ",
+            TokenType::TT_POW_EQ => "POW_EQ",
+            TokenType::TT_MOD_EQ => "MOD_EQ",
             TokenType::TT_EQ => "EQ",
             TokenType::TT_AT => "AT",
             TokenType::TT_LPAREN => "LPAREN",
@@ -65,7 +83,11 @@ impl Display for TokenType {
             TokenType::TT_LTE => "LTE",
             TokenType::TT_GTE => "GTE",
             TokenType::TT_COMMA => "COMMA",
+            TokenType::TT_COLON => "COLON",
             TokenType::TT_ARROW => "ARROW",
+            TokenType::TT_COALESCE => "COALESCE",
+            TokenType::TT_DOT => "DOT",
+            TokenType::TT_ELLIPSIS => "ELLIPSIS",
             TokenType::TT_NEWLINE => "NEWLINE",
             TokenType::TT_SEMI     => "SEMI",
             TokenType::TT_EOF => "EOF",
@@ -1,4 +1,5 @@
 mod errors;
+mod formatting;
 mod interpreting;
 mod lexing;
 mod nodes;
@@ -7,11 +8,16 @@ mod parsing;
 mod syntax;
 mod values;
 use crate::{
-    errors::standard_error::StandardError,
+    formatting::formatter::Formatter,
     interpreting::{context::Context, interpreter::Interpreter},
-    lexing::lexer::Lexer,
+    lexing::{lexer::Lexer, position::Position},
+    nodes::ast_node::AstNode,
     parsing::parser::Parser,
+    syntax::attributes::{BUILT_INS, STACK_SIZE},
+    values::value::Value,
 };
+pub use errors::{run_error::RunError, standard_error::StandardError};
+pub use interpreting::limits::Limits;
 pub use package_manager::{
     logs::{log_error, log_header, log_message, log_package_status},
     packages::{
@@ -20,45 +26,102 @@ pub use package_manager::{
     paths::get_package_path,
 };
 use simply_colored::*;
+use rustyline::{DefaultEditor, error::ReadlineError};
 use std::{
     cell::RefCell,
     fs,
-    io::{Write, stdin, stdout},
-    path::Path,
+    panic,
+    path::{Path, PathBuf},
     rc::Rc,
+    sync::Arc,
+    thread,
     time::Instant,
 };
 
-pub fn run(filename: &str, code: Option<String>) -> Option<StandardError> {
+// runs a file, or the code given when the filename is "<stdin>". gives back every syntax error
+// found, or the one error the program stopped on
+pub fn run(filename: &str, code: Option<String>) -> Result<(), RunError> {
+    run_with_limits(filename, code, Limits::default())
+}
+
+pub fn run_with_limits(
+    filename: &str,
+    code: Option<String>,
+    limits: Limits,
+) -> Result<(), RunError> {
     let contents = if filename == "<stdin>" {
         code.unwrap_or_default()
     } else {
         match fs::read_to_string(filename) {
             Ok(s) => s,
             Err(e) => {
-                println!("{DIM_RED}Failed to read provided '.maid' file: {e}{RESET}");
+                let pos = Position::new(0, 0, 0, filename, Arc::from(""));
 
-                return None;
+                return Err(RunError::from(StandardError::new(
+                    &format!("failed to read provided '.maid' file: {e}"),
+                    pos.clone(),
+                    pos,
+                    Some("check the path of the file you would like to run"),
+                )));
             }
         }
     };
 
     let start = Instant::now();
 
-    let mut lexer = Lexer::new(filename, contents.clone());
-    let token_result = lexer.make_tokens();
+    let program = parse(&mut Lexer::new(filename, contents))?;
+    let (mut interpreter, context) = new_interpreter(filename)?;
+
+    interpreter.limit(limits);
+    let result = interpreter.visit(program, context);
+    interpreter.release();
+
+    if cfg!(feature = "benchmark") {
+        println!("Time elapsed: {:?}ms", start.elapsed().as_millis());
+    }
 
-    if token_result.is_err() {
-        return token_result.err();
+    match result.error {
+        Some(error) => Err(RunError::from(error)),
+        None => Ok(()),
     }
+}
 
-    let mut parser = Parser::new(&token_result.ok().unwrap());
+// the parser and interpreter go a level deeper into the native stack for every level of
+// nesting and every function call, and a debug build takes far more stack per level than a
+// release one. runs up to MAX_CALL_DEPTH calls and MAX_NESTING_DEPTH levels deep need more
+// stack than a thread gets by default, so whoever runs maid calls it in here to have room
+pub fn on_large_stack<T: Send>(work: impl FnOnce() -> T + Send) -> T {
+    thread::scope(|scope| {
+        thread::Builder::new()
+            .stack_size(STACK_SIZE)
+            .spawn_scoped(scope, work)
+            .expect("failed to start a thread to run maid on")
+            .join()
+            .unwrap_or_else(|panic| panic::resume_unwind(panic))
+    })
+}
+
+// lexes and parses a program into its list of statements
+fn parse(lexer: &mut Lexer) -> Result<Box<AstNode>, Vec<StandardError>> {
+    let tokens = match lexer.make_tokens() {
+        Ok(tokens) => tokens,
+        Err(e) => return Err(vec![e]),
+    };
+
+    let mut parser = Parser::new(&tokens);
     let ast = parser.parse();
 
-    if ast.error.is_some() {
-        return ast.error;
+    if ast.has_failed() {
+        return Err(ast.errors);
     }
 
+    Ok(ast.node.unwrap())
+}
+
+// a fresh interpreter with the default library loaded, along with its program context
+fn new_interpreter(
+    filename: &str,
+) -> Result<(Interpreter, Rc<RefCell<Context>>), Vec<StandardError>> {
     let mut interpreter = Interpreter::new();
     let context = Rc::new(RefCell::new(Context::new(
         "<program>".to_string(),
@@ -66,47 +129,373 @@ pub fn run(filename: &str, code: Option<String>) -> Option<StandardError> {
         None,
     )));
     context.borrow_mut().symbol_table = Some(interpreter.global_symbol_table.clone());
+    interpreter.set_file_symbols(context.clone(), filename);
 
     if let Some(e) = interpreter.evaluate(
         "fetch _env(\"MAID_STD\") + \"/default/lib.maid\";",
         context.clone(),
     ) {
-        return Some(e);
+        return Err(vec![e]);
     }
 
-    let result = interpreter.visit(ast.node.unwrap(), context.clone());
+    Ok((interpreter, context))
+}
+
+// runs a line of REPL input, giving back the value of its last statement when that statement
+// is an expression worth echoing (not an assignment, definition or loop, and not null)
+fn execute_line(
+    interpreter: &mut Interpreter,
+    context: Rc<RefCell<Context>>,
+    filename: &str,
+    code: String,
+) -> Result<Option<Value>, Vec<StandardError>> {
+    let program = parse(&mut Lexer::new(filename, code))?;
+    let result = interpreter.visit(program.clone(), context);
 
-    if cfg!(feature = "benchmark") {
-        println!("Time elapsed: {:?}ms", start.elapsed().as_millis());
+    if let Some(e) = result.error {
+        return Err(vec![e]);
     }
 
-    result.error
+    let echoes = match program.as_ref() {
+        AstNode::List(list) => list.element_nodes.last().is_some_and(|last| {
+            !matches!(
+                last.as_ref(),
+                AstNode::VariableAssign(_)
+                    | AstNode::ConstAssign(_)
+                    | AstNode::FunctionDefinition(_)
+                    | AstNode::Import(_)
+                    | AstNode::Export(_)
+                    | AstNode::For(_)
+                    | AstNode::While(_)
+                    | AstNode::TryExcept(_)
+            )
+        }),
+        _ => false,
+    };
+
+    Ok(match result.value {
+        Some(Value::ListValue(values)) if echoes => values
+            .elements
+            .last()
+            .filter(|value| !matches!(value, Value::NullValue(_)))
+            .cloned(),
+        _ => None,
+    })
 }
 
+// runs a file a number of times and prints how long the runs took. returns whether every
+// run finished without an error
+pub fn bench(filename: &str, runs: usize, warmup: usize) -> bool {
+    for _ in 0..warmup {
+        if let Err(e) = run(filename, None) {
+            return report_errors(e.errors);
+        }
+    }
+
+    let mut times: Vec<f64> = Vec::new();
+
+    for _ in 0..runs {
+        let start = Instant::now();
+
+        if let Err(e) = run(filename, None) {
+            return report_errors(e.errors);
+        }
+
+        times.push(start.elapsed().as_secs_f64() * 1000.0);
+    }
+
+    if times.is_empty() {
+        return true;
+    }
+
+    let min = times.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = times.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let mean = times.iter().sum::<f64>() / times.len() as f64;
+
+    println!(
+        "{DIM_GREEN}{BOLD}bench:{RESET} {filename} ({runs} runs, {warmup} warmup)\n   min:  {min:.3}ms\n   mean: {mean:.3}ms\n   max:  {max:.3}ms"
+    );
+
+    true
+}
+
+// lexes and parses a file without running any of it, printing the syntax errors found.
+// returns whether there were none
+pub fn check(filename: &str) -> bool {
+    let contents = match fs::read_to_string(filename) {
+        Ok(s) => s,
+        Err(e) => {
+            println!("{DIM_RED}Failed to read provided '.maid' file: {e}{RESET}");
+
+            return false;
+        }
+    };
+
+    match parse(&mut Lexer::new(filename, contents)) {
+        Ok(_) => true,
+        Err(errors) => report_errors(errors),
+    }
+}
+
+// lists the functions a file defines at its top level along with their parameters, and the
+// '#' comment lines written right above each one. returns whether the file parsed
+pub fn doc(filename: &str) -> bool {
+    let contents = match fs::read_to_string(filename) {
+        Ok(s) => s,
+        Err(e) => {
+            println!("{DIM_RED}Failed to read provided '.maid' file: {e}{RESET}");
+
+            return false;
+        }
+    };
+
+    let mut lexer = Lexer::new(filename, contents).keeping_comments();
+    let program = match parse(&mut lexer) {
+        Ok(program) => program,
+        Err(errors) => return report_errors(errors),
+    };
+
+    let AstNode::List(statements) = *program else {
+        return true;
+    };
+
+    let mut formatter = Formatter::new("", Vec::new());
+
+    for statement in statements.element_nodes.iter() {
+        let function = match statement.as_ref() {
+            AstNode::FunctionDefinition(function) => function,
+            AstNode::Export(export) => match export.node_to_export.as_ref() {
+                AstNode::FunctionDefinition(function) => function,
+                _ => continue,
+            },
+            _ => continue,
+        };
+
+        let Some(name) = function.var_name_token.as_ref().and_then(|t| t.value.as_ref()) else {
+            continue;
+        };
+
+        let last = function.arg_name_tokens.len().saturating_sub(1);
+        let params: Vec<String> = function
+            .arg_name_tokens
+            .iter()
+            .enumerate()
+            .map(|(i, param)| {
+                let param_name = param.name_token.value.clone().unwrap_or_default();
+
+                match &param.default_node {
+                    Some(default) => format!("{param_name} = {}", formatter.expression(default)),
+                    None if function.is_variadic && i == last => format!("...{param_name}"),
+                    None => param_name,
+                }
+            })
+            .collect();
+
+        println!("{name}({})", params.join(", "));
+
+        for line in function.doc.iter().flat_map(|doc| doc.lines()) {
+            println!("    {line}");
+        }
+    }
+
+    true
+}
+
+// lays a program out the one way maid code is meant to look, keeping its comments. gives back
+// the syntax errors instead when it doesn't parse
+pub fn format(filename: &str, source: &str) -> Result<String, Vec<StandardError>> {
+    let mut lexer = Lexer::new(filename, source.to_string()).keeping_comments();
+    let program = parse(&mut lexer)?;
+    let mut formatter = Formatter::new(&lexer.text, std::mem::take(&mut lexer.comments));
+
+    Ok(formatter.format(&program))
+}
+
+// prints a file formatted, or writes it back formatted in place. a file with syntax errors
+// is left alone. returns whether it parsed
+pub fn fmt(filename: &str, in_place: bool) -> bool {
+    let contents = match fs::read_to_string(filename) {
+        Ok(s) => s,
+        Err(e) => {
+            println!("{DIM_RED}Failed to read provided '.maid' file: {e}{RESET}");
+
+            return false;
+        }
+    };
+
+    let formatted = match format(filename, &contents) {
+        Ok(formatted) => formatted,
+        Err(errors) => return report_errors(errors),
+    };
+
+    if !in_place {
+        print!("{formatted}");
+
+        return true;
+    }
+
+    if formatted != contents {
+        if let Err(e) = fs::write(filename, formatted) {
+            println!("{DIM_RED}Failed to write '{filename}': {e}{RESET}");
+
+            return false;
+        }
+    }
+
+    true
+}
+
+// prints the errors from a run, returns whether it went through cleanly
+fn report_errors(errors: Vec<StandardError>) -> bool {
+    for e in &errors {
+        println!("{e}");
+    }
+
+    errors.is_empty()
+}
+
+// REPL input is remembered between sessions in the user's data directory
+fn history_path() -> Option<PathBuf> {
+    let dir = dirs::data_local_dir().or_else(dirs::home_dir)?.join("maid");
+    fs::create_dir_all(&dir).ok()?;
+
+    Some(dir.join("history.txt"))
+}
+
+const REPL_HELP: &str = "Commands:
+   /help       show this message
+   /clear      clear the screen
+   /reset      forget everything defined so far
+   /types on   show the type of echoed results (/types off to hide it)
+   /save FILE  write the lines run so far this session to FILE
+   /load FILE  run FILE in this session
+   /exit       leave the REPL";
+
 pub fn launch_repl(version: &str) {
-    println!("Maid Code {version}\nType '/exit' to exit");
+    println!("Maid Code {version}\nType '/help' for a list of commands, '/exit' to exit");
+
+    let mut show_types = false;
+    let mut session = new_interpreter("<stdin>");
+    // lines that ran without errors, written out by /save
+    let mut lines: Vec<String> = Vec::new();
+
+    let mut editor = match DefaultEditor::new() {
+        Ok(editor) => editor,
+        Err(e) => return println!("{DIM_RED}Failed to start the REPL: {e}{RESET}"),
+    };
+
+    let history = history_path();
+
+    if let Some(history) = &history {
+        // there's no history yet on the first run
+        let _ = editor.load_history(history);
+    }
 
     loop {
-        let mut code = String::new();
+        let code = match editor.readline(">>> ") {
+            Ok(line) => line,
+            // ctrl+c drops the current line, ctrl+d leaves
+            Err(ReadlineError::Interrupted) => continue,
+            Err(_) => break,
+        };
 
-        print!(">>> ");
-        let _ = stdout().flush();
+        if !code.trim().is_empty() {
+            let _ = editor.add_history_entry(code.as_str());
 
-        stdin()
-            .read_line(&mut code)
-            .expect("Input text (stdin) was not a valid string");
+            if let Some(history) = &history {
+                if let Err(e) = editor.save_history(history) {
+                    println!("{DIM_RED}Failed to save REPL history: {e}{RESET}");
+                }
+            }
+        }
+
+        match code.trim() {
+            "/exit" => break,
+            "/help" => {
+                println!("{REPL_HELP}\n\nBuilt-in functions:\n   {}", BUILT_INS.join(", "));
+                continue;
+            }
+            "/clear" => {
+                print!("\x1B[2J\x1B[1;1H");
+                continue;
+            }
+            "/reset" => {
+                if let Ok((interpreter, _)) = &mut session {
+                    interpreter.release();
+                }
+
+                session = new_interpreter("<stdin>");
+                lines.clear();
+                continue;
+            }
+            "/types on" | "/types off" => {
+                show_types = code.trim() == "/types on";
+                continue;
+            }
+            command if command.starts_with("/save ") => {
+                let file = command["/save ".len()..].trim();
 
-        if code.trim() == "/exit" {
-            break;
+                match fs::write(file, lines.join("\n") + "\n") {
+                    Ok(_) => println!("Saved {} lines to '{file}'", lines.len()),
+                    Err(e) => println!("{DIM_RED}Failed to save '{file}': {e}{RESET}"),
+                }
+
+                continue;
+            }
+            _ => {}
         }
 
-        let error = run("<stdin>", Some(code));
+        let (interpreter, context) = match &mut session {
+            Ok((interpreter, context)) => (interpreter, context.clone()),
+            Err(errors) => {
+                for e in errors.iter() {
+                    println!("{e}");
+                }
+
+                continue;
+            }
+        };
 
-        if let Some(e) = error {
-            println!("{e}");
+        if let Some(file) = code.trim().strip_prefix("/load ") {
+            let file = file.trim();
+
+            let contents = match fs::read_to_string(file) {
+                Ok(contents) => contents,
+                Err(e) => {
+                    println!("{DIM_RED}Failed to read '{file}': {e}{RESET}");
+                    continue;
+                }
+            };
+
+            match execute_line(interpreter, context, file, contents.clone()) {
+                // kept line by line so /save counts the file's lines, not the file
+                Ok(_) => lines.extend(contents.trim_end().lines().map(String::from)),
+                Err(errors) => {
+                    for e in errors {
+                        println!("{e}");
+                    }
+                }
+            }
 
             continue;
         }
+
+        match execute_line(interpreter, context, "<stdin>", code.clone()) {
+            Ok(Some(value)) if show_types => {
+                println!("{} ({})", value.as_string(), value.object_type())
+            }
+            Ok(Some(value)) => println!("{}", value.as_string()),
+            Ok(None) => {}
+            Err(errors) => {
+                for e in errors {
+                    println!("{e}");
+                }
+
+                continue;
+            }
+        }
+
+        lines.push(code);
     }
 }
 
@@ -127,6 +516,8 @@ pub fn new_project(dir_name: &Path, init: bool) {
     "#,
         );
 
+    let _ = fs::write(dir_name.join("maid.toml"), "entry = \"home.maid\"\n");
+
     let _ = fs::write(
         dir_name.join("README.md"),
         "# Welcome to MaidCode!\nTo get started, see the documentation here.",
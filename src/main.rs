@@ -1,12 +1,16 @@
 use clap::{Parser, Subcommand};
+use serde::Deserialize;
 use std::{
     env,
     fs,
     path::{Path, PathBuf},
+    process,
+    time::Duration,
 };
 
 use maid_lang::{
-    create_package_dir, new_project, add_package, remove_package, update_package, run, launch_repl,
+    create_package_dir, new_project, add_package, remove_package, update_package,
+    run_with_limits, launch_repl, bench, check, doc, fmt, on_large_stack, Limits, RunError,
 };
 
 use include_dir::{include_dir, Dir};
@@ -20,10 +24,33 @@ const VERSION: &str = "2.6";
 struct Cli {
     /// Path to a .maid file to run
     file: Option<String>,
+    /// Run the file again every time it changes
+    #[arg(long, requires = "file")]
+    watch: bool,
+    /// Stop the file after this many operations, for running untrusted scripts
+    #[arg(long)]
+    max_operations: Option<usize>,
+    /// Stop the file after running for this many seconds
+    #[arg(long, value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+    /// Stop the file when a list grows past this many elements
+    #[arg(long)]
+    max_list_length: Option<usize>,
+    /// Stop the file when multiplying a string would build more than this many bytes
+    #[arg(long)]
+    max_repeat_length: Option<usize>,
     #[command(subcommand)]
     command: Option<Commands>,
 }
 
+/// Read a number of seconds like `2` or `0.5` given on the command line.
+fn parse_seconds(text: &str) -> Result<Duration, String> {
+    text.parse::<f64>()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or_else(|| format!("'{text}' isn't a positive number of seconds"))
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Create a maid project
@@ -36,6 +63,67 @@ enum Commands {
     Remove { name: String },
     /// Update an installed maid kennel to the latest version
     Update { name: String },
+    /// Check a .maid file for syntax errors without running it
+    Check { file: String },
+    /// List the functions a .maid file defines, with the comments above them
+    Doc { file: String },
+    /// Print a .maid file laid out in the standard style
+    Fmt {
+        file: String,
+        /// Write the formatted code back to the file instead of printing it
+        #[arg(long)]
+        in_place: bool,
+    },
+    /// Time a .maid file over several runs
+    Bench {
+        file: String,
+        /// Number of timed runs
+        #[arg(short, long, default_value_t = 10)]
+        runs: usize,
+        /// Number of untimed runs before timing starts
+        #[arg(short, long, default_value_t = 1)]
+        warmup: usize,
+    },
+}
+
+/// Project settings read from a `maid.toml`, paths are relative to the file.
+#[derive(Deserialize, Default)]
+struct Config {
+    /// File run by `maid` when no file is given
+    entry: Option<String>,
+    /// Standard library directory, used instead of the installed one
+    std: Option<String>,
+    /// Kennels directory, used instead of the installed one
+    kennels: Option<String>,
+    /// Features turned on for every run in the project, as if their flag was given
+    #[serde(default)]
+    features: Vec<String>,
+}
+
+/// Features a `maid.toml` can turn on.
+const FEATURES: &[&str] = &["watch"];
+
+/// Look for a `maid.toml` in the current directory or any of its parents.
+fn find_config() -> Option<(PathBuf, Config)> {
+    let cwd = env::current_dir().ok()?;
+
+    for dir in cwd.ancestors() {
+        let path = dir.join("maid.toml");
+
+        if path.is_file() {
+            let contents = fs::read_to_string(&path).ok()?;
+
+            return match toml::from_str::<Config>(&contents) {
+                Ok(config) => Some((dir.to_path_buf(), config)),
+                Err(e) => {
+                    eprintln!("Warning: ignoring invalid 'maid.toml': {e}");
+                    None
+                }
+            };
+        }
+    }
+
+    None
 }
 
 /// Ensure stdlib + kennels are available and point MAID_STD / MAID_PKG to them.
@@ -79,11 +167,115 @@ fn ensure_std_available() -> (PathBuf, PathBuf) {
     (std_path, pkg_path)
 }
 
+/// Print the errors a run stopped with, if any.
+fn print_errors(result: Result<(), RunError>) {
+    if let Err(err) = result {
+        println!("{err}");
+    }
+}
+
+/// Print the errors a run stopped with and exit with a failure, if there were any.
+fn report(result: Result<(), RunError>) {
+    if result.is_err() {
+        print_errors(result);
+        process::exit(1);
+    }
+}
+
+/// Run a file, then again whenever it's saved. Errors never stop the watching.
+#[cfg(feature = "watch")]
+fn watch(file: &str, limits: Limits) {
+    use notify::{recommended_watcher, EventKind, RecursiveMode, Watcher};
+    use std::sync::mpsc::channel;
+
+    let path = Path::new(file);
+    let (tx, rx) = channel();
+
+    let mut watcher = match recommended_watcher(tx) {
+        Ok(watcher) => watcher,
+        Err(e) => return eprintln!("Failed to watch '{file}': {e}"),
+    };
+
+    // editors often replace the file on save, so watch its directory instead
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+
+    if let Err(e) = watcher.watch(dir, RecursiveMode::NonRecursive) {
+        return eprintln!("Failed to watch '{file}': {e}");
+    }
+
+    let name = path.file_name();
+
+    print_errors(run_with_limits(file, None, limits));
+
+    while let Ok(event) = rx.recv() {
+        let changed = match event {
+            Ok(event) => {
+                matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+                    && event.paths.iter().any(|p| p.file_name() == name)
+            }
+            Err(e) => {
+                eprintln!("Watch error: {e}");
+                false
+            }
+        };
+
+        if !changed {
+            continue;
+        }
+
+        // a single save can fire several events, let them settle first
+        std::thread::sleep(Duration::from_millis(50));
+        let _ = rx.try_iter().count();
+
+        println!("\n--- '{file}' changed, running again ---\n");
+
+        print_errors(run_with_limits(file, None, limits));
+    }
+}
+
+#[cfg(not(feature = "watch"))]
+fn watch(_file: &str, _limits: Limits) {
+    eprintln!("'--watch' needs maid to be built with the 'watch' feature");
+}
+
 fn main() {
+    on_large_stack(cli)
+}
+
+fn cli() {
+    let (project_dir, config) = find_config().unwrap_or_default();
+    let (std_from_env, pkg_from_env) = (env::var("MAID_STD").is_ok(), env::var("MAID_PKG").is_ok());
+
     let _ = ensure_std_available(); // sets env + ensures files exist
+
+    // project paths win over the defaults, but not over the environment
+    if let (Some(std), false) = (&config.std, std_from_env) {
+        env::set_var("MAID_STD", project_dir.join(std));
+    }
+    if let (Some(kennels), false) = (&config.kennels, pkg_from_env) {
+        env::set_var("MAID_PKG", project_dir.join(kennels));
+    }
     create_package_dir();           // uses MAID_PKG
 
+    for feature in config.features.iter().filter(|feature| !FEATURES.contains(&feature.as_str())) {
+        eprintln!("Warning: ignoring unknown feature '{feature}' in 'maid.toml'");
+    }
+
     let cli = Cli::parse();
+    let watching = cli.watch || config.features.iter().any(|feature| feature == "watch");
+    let limits = Limits {
+        max_operations: cli.max_operations,
+        timeout: cli.timeout,
+        max_list_length: cli.max_list_length,
+        max_repeat_length: cli.max_repeat_length.or(Limits::default().max_repeat_length),
+    };
+    let limited = cli.max_operations.is_some()
+        || cli.timeout.is_some()
+        || cli.max_list_length.is_some()
+        || cli.max_repeat_length.is_some();
 
     match (cli.command, cli.file) {
         (Some(Commands::New { name }), _)      => new_project(Path::new(&name), false),
@@ -91,11 +283,28 @@ fn main() {
         (Some(Commands::Install { name }), _)  => add_package(&name),
         (Some(Commands::Remove  { name }), _)  => remove_package(&name),
         (Some(Commands::Update  { name }), _)  => update_package(&name),
-        (None, Some(file)) => {
-            if let Some(err) = run(&file, None) {
-                println!("{err}");
-            }
+        (Some(Commands::Check { file }), _) => process::exit(if check(&file) { 0 } else { 1 }),
+        (Some(Commands::Doc { file }), _)   => process::exit(if doc(&file) { 0 } else { 1 }),
+        (Some(Commands::Fmt { file, in_place }), _) => {
+            process::exit(if fmt(&file, in_place) { 0 } else { 1 })
+        }
+        (Some(Commands::Bench { file, runs, warmup }), _) => {
+            process::exit(if bench(&file, runs, warmup) { 0 } else { 1 })
         }
-        (None, None) => launch_repl(VERSION),
+        (None, Some(file)) if watching => watch(&file, limits),
+        (None, Some(file)) => report(run_with_limits(&file, None, limits)),
+        (None, None) => match config.entry {
+            Some(entry) if watching => watch(&project_dir.join(entry).to_string_lossy(), limits),
+            Some(entry) => {
+                report(run_with_limits(&project_dir.join(entry).to_string_lossy(), None, limits))
+            }
+            None => {
+                if limited {
+                    eprintln!("Warning: the run limits don't apply to the REPL");
+                }
+
+                launch_repl(VERSION)
+            }
+        },
     }
 }
@@ -1,31 +1,48 @@
 use crate::{
     lexing::position::Position,
     nodes::{
-        binary_operator_node::BinaryOperatorNode, break_node::BreakNode, call_node::CallNode,
-        const_assign_node::ConstAssignNode, continue_node::ContinueNode, for_node::ForNode,
-        function_definition_node::FunctionDefinitionNode, if_node::IfNode, import_node::ImportNode,
-        list_node::ListNode, number_node::NumberNode, return_node::ReturnNode,
-        string_node::StringNode, try_except_node::TryExceptNode,
-        unary_operator_node::UnaryOperatorNode, variable_access_node::VariableAccessNode,
-        variable_assign_node::VariableAssignNode, while_node::WhileNode,
+        binary_operator_node::BinaryOperatorNode, bool_node::BoolNode, break_node::BreakNode,
+        call_node::CallNode, compound_assign_node::CompoundAssignNode,
+        const_assign_node::ConstAssignNode, continue_node::ContinueNode, export_node::ExportNode,
+        for_in_node::ForInNode, for_node::ForNode, function_definition_node::FunctionDefinitionNode,
+        if_node::IfNode, import_node::ImportNode, interp_string_node::InterpStringNode,
+        list_node::ListNode, map_node::MapNode, match_node::MatchNode,
+        method_call_node::MethodCallNode, null_node::NullNode, number_node::NumberNode,
+        property_access_node::PropertyAccessNode, property_assign_node::PropertyAssignNode,
+        return_node::ReturnNode, string_node::StringNode, struct_def_node::StructDefNode,
+        try_except_node::TryExceptNode, unary_operator_node::UnaryOperatorNode,
+        variable_access_node::VariableAccessNode, variable_assign_node::VariableAssignNode,
+        while_node::WhileNode,
     },
 };
 
 #[derive(Debug, Clone)]
 pub enum AstNode {
     BinaryOperator(BinaryOperatorNode),
+    Bool(BoolNode),
     Break(BreakNode),
     Call(CallNode),
+    CompoundAssign(CompoundAssignNode),
     ConstAssign(ConstAssignNode),
     Continue(ContinueNode),
+    Export(ExportNode),
     For(ForNode),
+    ForIn(ForInNode),
     FunctionDefinition(FunctionDefinitionNode),
     If(IfNode),
     Import(ImportNode),
+    InterpString(InterpStringNode),
     List(ListNode),
+    Map(MapNode),
+    Match(MatchNode),
+    MethodCall(MethodCallNode),
+    Null(NullNode),
     Number(NumberNode),
+    PropertyAccess(PropertyAccessNode),
+    PropertyAssign(PropertyAssignNode),
     Return(ReturnNode),
     Strings(StringNode),
+    StructDef(StructDefNode),
     TryExcept(TryExceptNode),
     UnaryOperator(UnaryOperatorNode),
     VariableAccess(VariableAccessNode),
@@ -37,18 +54,30 @@ impl AstNode {
     pub fn position_start(&self) -> Option<Position> {
         match self {
             AstNode::BinaryOperator(node) => node.pos_start.clone(),
+            AstNode::Bool(node) => node.pos_start.clone(),
             AstNode::Break(node) => node.pos_start.clone(),
             AstNode::Call(node) => node.pos_start.clone(),
+            AstNode::CompoundAssign(node) => node.pos_start.clone(),
             AstNode::ConstAssign(node) => node.pos_start.clone(),
             AstNode::Continue(node) => node.pos_start.clone(),
+            AstNode::Export(node) => node.pos_start.clone(),
             AstNode::For(node) => node.pos_start.clone(),
+            AstNode::ForIn(node) => node.pos_start.clone(),
             AstNode::FunctionDefinition(node) => node.pos_start.clone(),
             AstNode::If(node) => node.pos_start.clone(),
             AstNode::Import(node) => node.pos_start.clone(),
+            AstNode::InterpString(node) => node.pos_start.clone(),
             AstNode::List(node) => node.pos_start.clone(),
+            AstNode::Map(node) => node.pos_start.clone(),
+            AstNode::Match(node) => node.pos_start.clone(),
+            AstNode::MethodCall(node) => node.pos_start.clone(),
+            AstNode::Null(node) => node.pos_start.clone(),
             AstNode::Number(node) => node.pos_start.clone(),
+            AstNode::PropertyAccess(node) => node.pos_start.clone(),
+            AstNode::PropertyAssign(node) => node.pos_start.clone(),
             AstNode::Return(node) => node.pos_start.clone(),
             AstNode::Strings(node) => node.pos_start.clone(),
+            AstNode::StructDef(node) => node.pos_start.clone(),
             AstNode::TryExcept(node) => node.pos_start.clone(),
             AstNode::UnaryOperator(node) => node.pos_start.clone(),
             AstNode::VariableAccess(node) => node.pos_start.clone(),
@@ -60,18 +89,30 @@ impl AstNode {
     pub fn position_end(&self) -> Option<Position> {
         match self {
             AstNode::BinaryOperator(node) => node.pos_end.clone(),
+            AstNode::Bool(node) => node.pos_end.clone(),
             AstNode::Break(node) => node.pos_end.clone(),
             AstNode::Call(node) => node.pos_end.clone(),
+            AstNode::CompoundAssign(node) => node.pos_end.clone(),
             AstNode::ConstAssign(node) => node.pos_end.clone(),
             AstNode::Continue(node) => node.pos_end.clone(),
+            AstNode::Export(node) => node.pos_end.clone(),
             AstNode::For(node) => node.pos_end.clone(),
+            AstNode::ForIn(node) => node.pos_end.clone(),
             AstNode::FunctionDefinition(node) => node.pos_end.clone(),
             AstNode::If(node) => node.pos_end.clone(),
             AstNode::Import(node) => node.pos_end.clone(),
+            AstNode::InterpString(node) => node.pos_end.clone(),
             AstNode::List(node) => node.pos_end.clone(),
+            AstNode::Map(node) => node.pos_end.clone(),
+            AstNode::Match(node) => node.pos_end.clone(),
+            AstNode::MethodCall(node) => node.pos_end.clone(),
+            AstNode::Null(node) => node.pos_end.clone(),
             AstNode::Number(node) => node.pos_end.clone(),
+            AstNode::PropertyAccess(node) => node.pos_end.clone(),
+            AstNode::PropertyAssign(node) => node.pos_end.clone(),
             AstNode::Return(node) => node.pos_end.clone(),
             AstNode::Strings(node) => node.pos_end.clone(),
+            AstNode::StructDef(node) => node.pos_end.clone(),
             AstNode::TryExcept(node) => node.pos_end.clone(),
             AstNode::UnaryOperator(node) => node.pos_end.clone(),
             AstNode::VariableAccess(node) => node.pos_end.clone(),
@@ -3,13 +3,13 @@ use crate::{lexing::position::Position, nodes::ast_node::AstNode};
 #[derive(Debug, Clone)]
 pub struct CallNode {
     pub node_to_call: Box<AstNode>,
-    pub arg_nodes: Vec<Box<AstNode>>,
+    pub arg_nodes: Vec<AstNode>,
     pub pos_start: Option<Position>,
     pub pos_end: Option<Position>,
 }
 
 impl CallNode {
-    pub fn new(node_to_call: Box<AstNode>, arg_nodes: Vec<Box<AstNode>>) -> Self {
+    pub fn new(node_to_call: Box<AstNode>, arg_nodes: Vec<AstNode>) -> Self {
         Self {
             node_to_call: node_to_call.to_owned(),
             arg_nodes: arg_nodes.to_owned(),
@@ -0,0 +1,25 @@
+use crate::{
+    lexing::{position::Position, token::Token},
+    nodes::ast_node::AstNode,
+};
+
+#[derive(Debug, Clone)]
+pub struct CompoundAssignNode {
+    pub var_name_token: Token,
+    pub op_token: Token,
+    pub value_node: Box<AstNode>,
+    pub pos_start: Option<Position>,
+    pub pos_end: Option<Position>,
+}
+
+impl CompoundAssignNode {
+    pub fn new(var_name_token: Token, op_token: Token, value_node: Box<AstNode>) -> Self {
+        Self {
+            var_name_token: var_name_token.to_owned(),
+            op_token,
+            pos_start: var_name_token.pos_start,
+            pos_end: value_node.position_end(),
+            value_node,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{lexing::position::Position, nodes::ast_node::AstNode};
+
+#[derive(Debug, Clone)]
+pub struct ExportNode {
+    pub node_to_export: Box<AstNode>,
+    pub export_name: String,
+    pub pos_start: Option<Position>,
+    pub pos_end: Option<Position>,
+}
+
+impl ExportNode {
+    pub fn new(
+        node_to_export: Box<AstNode>,
+        export_name: String,
+        pos_start: Option<Position>,
+    ) -> Self {
+        Self {
+            pos_end: node_to_export.position_end(),
+            node_to_export,
+            export_name,
+            pos_start,
+        }
+    }
+}
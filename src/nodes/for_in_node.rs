@@ -0,0 +1,29 @@
+use crate::{
+    lexing::{position::Position, token::Token},
+    nodes::ast_node::AstNode,
+};
+
+#[derive(Debug, Clone)]
+pub struct ForInNode {
+    pub var_name_token: Token,
+    pub iterable_node: Box<AstNode>,
+    pub body_node: Box<AstNode>,
+    pub pos_start: Option<Position>,
+    pub pos_end: Option<Position>,
+}
+
+impl ForInNode {
+    pub fn new(
+        var_name_token: Token,
+        iterable_node: Box<AstNode>,
+        body_node: Box<AstNode>,
+    ) -> Self {
+        Self {
+            var_name_token: var_name_token.to_owned(),
+            iterable_node,
+            body_node,
+            pos_start: var_name_token.pos_start,
+            pos_end: var_name_token.pos_end,
+        }
+    }
+}
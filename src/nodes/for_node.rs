@@ -9,6 +9,8 @@ pub struct ForNode {
     pub start_value_node: Box<AstNode>,
     pub end_value_node: Box<AstNode>,
     pub step_value_node: Option<Box<AstNode>>,
+    // 'to' ranges include their end value, 'through' ranges stop before it
+    pub inclusive: bool,
     pub body_node: Box<AstNode>,
     pub pos_start: Option<Position>,
     pub pos_end: Option<Position>,
@@ -20,6 +22,7 @@ impl ForNode {
         start_value_node: Box<AstNode>,
         end_value_node: Box<AstNode>,
         step_value_node: Option<Box<AstNode>>,
+        inclusive: bool,
         body_node: Box<AstNode>,
     ) -> Self {
         Self {
@@ -27,6 +30,7 @@ impl ForNode {
             start_value_node,
             end_value_node,
             step_value_node,
+            inclusive,
             body_node,
             pos_start: var_name_token.pos_start,
             pos_end: var_name_token.pos_end,
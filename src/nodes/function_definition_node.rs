@@ -4,12 +4,32 @@ use crate::{
 };
 use std::sync::Arc;
 
+// a function argument along with the expression used when a call leaves it out, if any
+#[derive(Debug, Clone)]
+pub struct DefaultParam {
+    pub name_token: Token,
+    pub default_node: Option<Box<AstNode>>,
+}
+
+impl DefaultParam {
+    pub fn new(name_token: Token, default_node: Option<Box<AstNode>>) -> Self {
+        Self {
+            name_token,
+            default_node,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FunctionDefinitionNode {
     pub var_name_token: Option<Token>,
-    pub arg_name_tokens: Arc<[Token]>,
+    pub arg_name_tokens: Arc<[DefaultParam]>,
+    // the last argument collects the extra arguments of a call
+    pub is_variadic: bool,
     pub body_node: Box<AstNode>,
     pub should_auto_return: bool,
+    // the comment written above the definition, if the lexer kept it
+    pub doc: Option<String>,
     pub pos_start: Option<Position>,
     pub pos_end: Option<Position>,
 }
@@ -17,19 +37,23 @@ pub struct FunctionDefinitionNode {
 impl FunctionDefinitionNode {
     pub fn new(
         var_name_token: Option<Token>,
-        arg_name_tokens: &[Token],
+        arg_name_tokens: &[DefaultParam],
+        is_variadic: bool,
         body_node: Box<AstNode>,
         should_auto_return: bool,
+        doc: Option<String>,
     ) -> Self {
         Self {
             var_name_token: var_name_token.to_owned(),
             arg_name_tokens: Arc::from(arg_name_tokens),
+            is_variadic,
             body_node: body_node.to_owned(),
             should_auto_return,
+            doc,
             pos_start: if var_name_token.is_some() {
                 var_name_token.unwrap().pos_end
             } else if !arg_name_tokens.is_empty() {
-                arg_name_tokens[0].pos_start.to_owned()
+                arg_name_tokens[0].name_token.pos_start.to_owned()
             } else {
                 body_node.position_start()
             },
@@ -1,18 +1,27 @@
-use crate::{lexing::position::Position, nodes::ast_node::AstNode};
+use crate::{
+    lexing::{position::Position, token::Token},
+    nodes::ast_node::AstNode,
+};
 
 #[derive(Debug, Clone)]
 pub struct ImportNode {
     pub node_to_import: Box<AstNode>,
+    pub alias_token: Option<Token>,
     pub pos_start: Option<Position>,
     pub pos_end: Option<Position>,
 }
 
 impl ImportNode {
-    pub fn new(node_to_import: Box<AstNode>) -> Self {
+    pub fn new(node_to_import: Box<AstNode>, alias_token: Option<Token>) -> Self {
         Self {
             node_to_import: node_to_import.to_owned(),
             pos_start: node_to_import.position_start(),
-            pos_end: node_to_import.position_end(),
+            pos_end: if alias_token.is_some() {
+                alias_token.as_ref().unwrap().pos_end.clone()
+            } else {
+                node_to_import.position_end()
+            },
+            alias_token,
         }
     }
 }
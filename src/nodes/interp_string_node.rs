@@ -0,0 +1,24 @@
+use crate::{lexing::position::Position, nodes::ast_node::AstNode};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct InterpStringNode {
+    // literal parts are string nodes, in between them the expressions from each '{...}'
+    pub segments: Arc<[Box<AstNode>]>,
+    pub pos_start: Option<Position>,
+    pub pos_end: Option<Position>,
+}
+
+impl InterpStringNode {
+    pub fn new(
+        segments: &[Box<AstNode>],
+        pos_start: Option<Position>,
+        pos_end: Option<Position>,
+    ) -> Self {
+        Self {
+            segments: Arc::from(segments),
+            pos_start,
+            pos_end,
+        }
+    }
+}
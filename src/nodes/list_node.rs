@@ -4,6 +4,9 @@ use std::sync::Arc;
 #[derive(Debug, Clone)]
 pub struct ListNode {
     pub element_nodes: Arc<[Box<AstNode>]>,
+    // a block or a whole program is a list of its statements, which isn't held to the limit
+    // on list lengths the way a '[...]' list is
+    pub holds_statements: bool,
     pub pos_start: Option<Position>,
     pub pos_end: Option<Position>,
 }
@@ -11,11 +14,13 @@ pub struct ListNode {
 impl ListNode {
     pub fn new(
         element_nodes: &[Box<AstNode>],
+        holds_statements: bool,
         pos_start: Option<Position>,
         pos_end: Option<Position>,
     ) -> Self {
         Self {
             element_nodes: Arc::from(element_nodes),
+            holds_statements,
             pos_start,
             pos_end,
         }
@@ -0,0 +1,23 @@
+use crate::{lexing::position::Position, nodes::ast_node::AstNode};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct MapNode {
+    pub entry_nodes: Arc<[(Box<AstNode>, Box<AstNode>)]>,
+    pub pos_start: Option<Position>,
+    pub pos_end: Option<Position>,
+}
+
+impl MapNode {
+    pub fn new(
+        entry_nodes: &[(Box<AstNode>, Box<AstNode>)],
+        pos_start: Option<Position>,
+        pos_end: Option<Position>,
+    ) -> Self {
+        Self {
+            entry_nodes: Arc::from(entry_nodes),
+            pos_start,
+            pos_end,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{lexing::position::Position, nodes::ast_node::AstNode};
+
+#[derive(Debug, Clone)]
+pub struct MatchNode {
+    pub subject_node: Box<AstNode>,
+    // (pattern, body) pairs, tried in order
+    pub cases: Vec<(Box<AstNode>, Box<AstNode>)>,
+    // the body of the '_' case, used when no pattern is equal to the subject
+    pub default_case: Option<Box<AstNode>>,
+    pub pos_start: Option<Position>,
+    pub pos_end: Option<Position>,
+}
+
+impl MatchNode {
+    pub fn new(
+        subject_node: Box<AstNode>,
+        cases: Vec<(Box<AstNode>, Box<AstNode>)>,
+        default_case: Option<Box<AstNode>>,
+        pos_start: Option<Position>,
+        pos_end: Option<Position>,
+    ) -> Self {
+        Self {
+            subject_node,
+            cases,
+            default_case,
+            pos_start,
+            pos_end,
+        }
+    }
+}
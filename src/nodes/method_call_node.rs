@@ -0,0 +1,27 @@
+use crate::{lexing::position::Position, nodes::ast_node::AstNode};
+
+#[derive(Debug, Clone)]
+pub struct MethodCallNode {
+    pub receiver_node: Box<AstNode>,
+    pub method_node: Box<AstNode>,
+    pub arg_nodes: Vec<AstNode>,
+    pub pos_start: Option<Position>,
+    pub pos_end: Option<Position>,
+}
+
+impl MethodCallNode {
+    pub fn new(
+        receiver_node: Box<AstNode>,
+        method_node: Box<AstNode>,
+        arg_nodes: Vec<AstNode>,
+        pos_end: Option<Position>,
+    ) -> Self {
+        Self {
+            pos_start: receiver_node.position_start(),
+            receiver_node,
+            method_node,
+            arg_nodes,
+            pos_end,
+        }
+    }
+}
@@ -1,17 +1,29 @@
 pub mod ast_node;
 pub mod binary_operator_node;
+pub mod bool_node;
 pub mod break_node;
 pub mod call_node;
+pub mod compound_assign_node;
 pub mod const_assign_node;
 pub mod continue_node;
+pub mod export_node;
+pub mod for_in_node;
 pub mod for_node;
 pub mod function_definition_node;
 pub mod if_node;
 pub mod import_node;
+pub mod interp_string_node;
 pub mod list_node;
+pub mod map_node;
+pub mod match_node;
+pub mod method_call_node;
+pub mod null_node;
 pub mod number_node;
+pub mod property_access_node;
+pub mod property_assign_node;
 pub mod return_node;
 pub mod string_node;
+pub mod struct_def_node;
 pub mod try_except_node;
 pub mod unary_operator_node;
 pub mod variable_access_node;
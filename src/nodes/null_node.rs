@@ -0,0 +1,16 @@
+use crate::lexing::{position::Position, token::Token};
+
+#[derive(Debug, Clone)]
+pub struct NullNode {
+    pub pos_start: Option<Position>,
+    pub pos_end: Option<Position>,
+}
+
+impl NullNode {
+    pub fn new(token: Token) -> Self {
+        Self {
+            pos_start: token.pos_start,
+            pos_end: token.pos_end,
+        }
+    }
+}
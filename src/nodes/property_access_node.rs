@@ -0,0 +1,20 @@
+use crate::{lexing::position::Position, nodes::ast_node::AstNode};
+
+#[derive(Debug, Clone)]
+pub struct PropertyAccessNode {
+    pub object: Box<AstNode>,
+    pub property: String,
+    pub pos_start: Option<Position>,
+    pub pos_end: Option<Position>,
+}
+
+impl PropertyAccessNode {
+    pub fn new(object: Box<AstNode>, property: String, pos_end: Option<Position>) -> Self {
+        Self {
+            pos_start: object.position_start(),
+            object,
+            property,
+            pos_end,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{
+    lexing::{position::Position, token::Token},
+    nodes::ast_node::AstNode,
+};
+
+#[derive(Debug, Clone)]
+pub struct PropertyAssignNode {
+    pub var_name_token: Token,
+    pub property_token: Token,
+    pub value_node: Box<AstNode>,
+    pub pos_start: Option<Position>,
+    pub pos_end: Option<Position>,
+}
+
+impl PropertyAssignNode {
+    pub fn new(var_name_token: Token, property_token: Token, value_node: Box<AstNode>) -> Self {
+        Self {
+            pos_start: var_name_token.pos_start.clone(),
+            pos_end: value_node.position_end(),
+            var_name_token,
+            property_token,
+            value_node,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::lexing::{position::Position, token::Token};
+
+#[derive(Debug, Clone)]
+pub struct StructDefNode {
+    pub name_token: Token,
+    pub field_name_tokens: Vec<Token>,
+    pub pos_start: Option<Position>,
+    pub pos_end: Option<Position>,
+}
+
+impl StructDefNode {
+    pub fn new(
+        name_token: Token,
+        field_name_tokens: Vec<Token>,
+        pos_start: Option<Position>,
+        pos_end: Option<Position>,
+    ) -> Self {
+        Self {
+            name_token,
+            field_name_tokens,
+            pos_start,
+            pos_end,
+        }
+    }
+}
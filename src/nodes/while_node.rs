@@ -4,6 +4,8 @@ use crate::{lexing::position::Position, nodes::ast_node::AstNode};
 pub struct WhileNode {
     pub condition_node: Box<AstNode>,
     pub body_node: Box<AstNode>,
+    // an 'until' loop, running while the condition is falsy instead
+    pub until: bool,
     pub pos_start: Option<Position>,
     pub pos_end: Option<Position>,
 }
@@ -13,8 +15,16 @@ impl WhileNode {
         Self {
             condition_node: condition_node.clone(),
             body_node: body_node.clone(),
+            until: false,
             pos_start: condition_node.position_start(),
             pos_end: body_node.position_end(),
         }
     }
+
+    pub fn until(condition_node: Box<AstNode>, body_node: Box<AstNode>) -> Self {
+        Self {
+            until: true,
+            ..Self::new(condition_node, body_node)
+        }
+    }
 }
@@ -41,7 +41,9 @@ pub fn create_package_dir() {
 obj std_os = _env(\"MAID_STD\") + \"/std/os.maid\";
 obj std_hashmap = _env(\"MAID_STD\") + \"/std/hashmap.maid\";
 obj std_format = _env(\"MAID_STD\") + \"/std/format.maid\";
-obj std_math = _env(\"MAID_STD\") + \"/std/math.maid\";",
+obj std_math = _env(\"MAID_STD\") + \"/std/math.maid\";
+obj std_dispatch = _env(\"MAID_STD\") + \"/std/dispatch.maid\";
+obj std_testing = _env(\"MAID_STD\") + \"/std/testing.maid\";",
         );
     }
 }
@@ -2,21 +2,23 @@ use crate::{errors::standard_error::StandardError, nodes::ast_node::AstNode};
 
 #[derive(Clone)]
 pub struct ParseResult {
-    pub error: Option<StandardError>,
+    // the error parsing stopped at. once the whole program is parsed, every error it ran into
+    pub errors: Vec<StandardError>,
+    // set when nesting ran too deep, an error no caller should swap for a more general one
+    pub too_deep: bool,
     pub node: Option<Box<AstNode>>,
     pub last_registered_advance_count: usize,
     pub advance_count: usize,
-    pub to_reverse_count: usize,
 }
 
 impl ParseResult {
     pub fn new() -> Self {
         Self {
-            error: None,
+            errors: Vec::new(),
+            too_deep: false,
             node: None,
             last_registered_advance_count: 0,
             advance_count: 0,
-            to_reverse_count: 0,
         }
     }
 
@@ -29,23 +31,14 @@ impl ParseResult {
         self.last_registered_advance_count = parse_result.advance_count;
         self.advance_count += parse_result.advance_count;
 
-        if parse_result.error.is_some() {
-            self.error = parse_result.error
+        if parse_result.has_failed() {
+            self.errors = parse_result.errors;
+            self.too_deep = parse_result.too_deep;
         }
 
         parse_result.node
     }
 
-    pub fn try_register(&mut self, parse_result: ParseResult) -> Option<Box<AstNode>> {
-        if parse_result.error.is_some() {
-            self.to_reverse_count = parse_result.advance_count;
-
-            return None;
-        }
-
-        self.register(parse_result)
-    }
-
     pub fn success(&mut self, node: Option<Box<AstNode>>) -> ParseResult {
         self.node = node;
 
@@ -53,10 +46,14 @@ impl ParseResult {
     }
 
     pub fn failure(&mut self, error: Option<StandardError>) -> ParseResult {
-        if self.error.is_none() || self.last_registered_advance_count == 0 {
-            self.error = error
+        if !self.has_failed() || (self.last_registered_advance_count == 0 && !self.too_deep) {
+            self.errors = error.into_iter().collect();
         }
 
         self.clone()
     }
+
+    pub fn has_failed(&self) -> bool {
+        !self.errors.is_empty()
+    }
 }
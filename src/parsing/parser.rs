@@ -1,23 +1,32 @@
 use crate::{
     errors::standard_error::StandardError,
-    lexing::{position::Position, token::Token, token_type::TokenType},
+    lexing::{lexer::Lexer, position::Position, token::Token, token_type::TokenType},
     nodes::{
-        ast_node::AstNode, binary_operator_node::BinaryOperatorNode, break_node::BreakNode,
-        call_node::CallNode, const_assign_node::ConstAssignNode, continue_node::ContinueNode,
-        for_node::ForNode, function_definition_node::FunctionDefinitionNode, if_node::IfNode,
-        import_node::ImportNode, list_node::ListNode, number_node::NumberNode,
-        return_node::ReturnNode, string_node::StringNode, try_except_node::TryExceptNode,
+        ast_node::AstNode, binary_operator_node::BinaryOperatorNode, bool_node::BoolNode,
+        break_node::BreakNode, call_node::CallNode, compound_assign_node::CompoundAssignNode,
+        const_assign_node::ConstAssignNode, continue_node::ContinueNode, export_node::ExportNode,
+        for_in_node::ForInNode, for_node::ForNode,
+        function_definition_node::{DefaultParam, FunctionDefinitionNode}, if_node::IfNode,
+        import_node::ImportNode, interp_string_node::InterpStringNode, list_node::ListNode,
+        map_node::MapNode, match_node::MatchNode, method_call_node::MethodCallNode,
+        null_node::NullNode, number_node::NumberNode, property_access_node::PropertyAccessNode,
+        property_assign_node::PropertyAssignNode, return_node::ReturnNode,
+        string_node::StringNode, struct_def_node::StructDefNode, try_except_node::TryExceptNode,
         unary_operator_node::UnaryOperatorNode, variable_access_node::VariableAccessNode,
         variable_assign_node::VariableAssignNode, while_node::WhileNode,
     },
     parsing::parse_result::ParseResult,
+    syntax::attributes::MAX_NESTING_DEPTH,
 };
-use std::sync::Arc;
+use std::{mem, sync::Arc};
 
 pub struct Parser {
     pub tokens: Arc<[Token]>,
     pub token_index: isize,
     pub current_token: Option<Token>,
+    pub depth: usize,
+    // errors from statements parsing skipped past, reported along with the rest by 'parse'
+    pub errors: Vec<StandardError>,
 }
 
 impl Parser {
@@ -26,6 +35,8 @@ impl Parser {
             tokens: Arc::from(tokens),
             token_index: -1,
             current_token: None,
+            depth: 0,
+            errors: Vec::new(),
         };
         parser.advance();
 
@@ -39,11 +50,8 @@ impl Parser {
         self.current_token.clone()
     }
 
-    pub fn reverse(&mut self, amount: usize) -> Option<Token> {
-        self.token_index -= amount as isize;
-        self.update_current_token();
-
-        self.current_token.clone()
+    pub fn peek(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get((self.token_index + offset as isize) as usize)
     }
 
     #[inline]
@@ -58,6 +66,49 @@ impl Parser {
     }
 
 
+    pub fn nested(&mut self, parse: fn(&mut Parser) -> ParseResult) -> ParseResult {
+        if self.depth >= MAX_NESTING_DEPTH {
+            let mut parse_result = ParseResult::new();
+            parse_result.too_deep = true;
+
+            return parse_result.failure(Some(StandardError::new(
+                "nesting too deep",
+                self.current_pos_start(),
+                self.current_pos_end(),
+                Some("split the expression up into smaller objects"),
+            )));
+        }
+
+        self.depth += 1;
+        let parse_result = parse(self);
+        self.depth -= 1;
+
+        parse_result
+    }
+
+    // skip ahead to the end of the broken statement so parsing can carry on
+    // and report any further errors in the same run
+    pub fn synchronize(&mut self, parse_result: &mut ParseResult) {
+        self.errors.append(&mut parse_result.errors);
+        parse_result.too_deep = false;
+
+        let mut depth: usize = 0;
+
+        loop {
+            match self.current_token_ref().token_type {
+                TokenType::TT_EOF => break,
+                TokenType::TT_NEWLINE | TokenType::TT_SEMI if depth == 0 => break,
+                TokenType::TT_RBRACKET if depth == 0 => break,
+                TokenType::TT_RBRACKET => depth -= 1,
+                TokenType::TT_LBRACKET => depth += 1,
+                _ => {}
+            }
+
+            parse_result.register_advancement();
+            self.advance();
+        }
+    }
+
     pub fn update_current_token(&mut self) {
         if self.token_index >= 0 && self.token_index < self.tokens.len() as isize {
             self.current_token = Some(self.tokens[self.token_index as usize].clone());
@@ -95,9 +146,12 @@ impl Parser {
     pub fn parse(&mut self) -> ParseResult {
         let mut parse_result = self.statements();
 
-        if parse_result.error.is_some() && self.current_token_copy().token_type != TokenType::TT_EOF
-        {
-            return parse_result.failure(Some(StandardError::new(
+        // the errors statements were skipped for came first
+        parse_result.errors.splice(0..0, mem::take(&mut self.errors));
+
+        // statements stop at a stray '}', anything after it was never parsed
+        if !parse_result.has_failed() && self.current_token_copy().token_type != TokenType::TT_EOF {
+            parse_result.failure(Some(StandardError::new(
                 "expected operator or bracket",
                 self.current_pos_start(),
                 self.current_pos_end(),
@@ -119,22 +173,19 @@ impl Parser {
             parse_result.register_advancement();
             self.advance();
 
-            let node = parse_result
-                .register(self.comparison_expr())
-                .unwrap()
-                .clone();
+            let node = parse_result.register(self.nested(Self::comparison_expr));
 
-            if parse_result.error.is_some() {
+            if parse_result.has_failed() {
                 return parse_result;
             }
 
             return parse_result.success(Some(Box::new(AstNode::UnaryOperator(
-                UnaryOperatorNode::new(op_token.clone(), node.clone()),
+                UnaryOperatorNode::new(op_token.clone(), node.unwrap()),
             ))));
         }
 
         let node = parse_result.register(self.binary_operator(
-            "arithmetic_expr",
+            "coalesce_expr",
             &[
                 (TokenType::TT_EE, ""),
                 (TokenType::TT_NE, ""),
@@ -142,11 +193,13 @@ impl Parser {
                 (TokenType::TT_GT, ""),
                 (TokenType::TT_LTE, ""),
                 (TokenType::TT_GTE, ""),
+                (TokenType::TT_KEYWORD, "in"),
+                (TokenType::TT_KEYWORD, "not"),
             ],
             None,
         ));
 
-        if parse_result.error.is_some() {
+        if parse_result.has_failed() {
             return parse_result.failure(Some(StandardError::new(
                 "expected an object or operator",
                 self.current_pos_start(),
@@ -158,6 +211,11 @@ impl Parser {
         parse_result.success(node)
     }
 
+    // binds tighter than comparisons, so `count ?? 0 > 5` compares the fallback
+    pub fn coalesce_expr(&mut self) -> ParseResult {
+        self.binary_operator("arithmetic_expr", &[(TokenType::TT_COALESCE, "")], None)
+    }
+
     pub fn arithmetic_expr(&mut self) -> ParseResult {
         self.binary_operator(
             "term",
@@ -191,7 +249,7 @@ impl Parser {
         } else {
             let element = parse_result.register(self.expr());
 
-            if parse_result.error.is_some() {
+            if parse_result.has_failed() {
                 return parse_result.failure(Some(StandardError::new(
                     "expected closing bracket or list element",
                     self.current_pos_start(),
@@ -210,7 +268,7 @@ impl Parser {
 
                 let element = parse_result.register(self.expr());
 
-                if parse_result.error.is_some() {
+                if parse_result.has_failed() {
                     return parse_result;
                 }
 
@@ -234,17 +292,111 @@ impl Parser {
 
         parse_result.success(Some(Box::new(AstNode::List(ListNode::new(
             &element_nodes,
+            false,
             pos_start,
             self.current_token_copy().pos_end,
         )))))
     }
 
+    pub fn map_expr(&mut self) -> ParseResult {
+        let mut parse_result = ParseResult::new();
+        let mut entry_nodes: Vec<(Box<AstNode>, Box<AstNode>)> = Vec::new();
+        let pos_start = self.current_token_ref().pos_start.clone();
+
+        if self.current_token_ref().token_type != TokenType::TT_LBRACKET {
+            return parse_result.failure(Some(StandardError::new(
+                "expected map initializing bracket",
+                self.current_pos_start(),
+                self.current_pos_end(),
+                Some("add a '{' to start the map"),
+            )));
+        }
+
+        parse_result.register_advancement();
+        self.advance();
+
+        self.skip_separators(&mut parse_result);
+
+        while self.current_token_ref().token_type != TokenType::TT_RBRACKET {
+            if !entry_nodes.is_empty() {
+                if self.current_token_ref().token_type != TokenType::TT_COMMA {
+                    return parse_result.failure(Some(StandardError::new(
+                        "expected closing bracket or next map entry",
+                        self.current_pos_start(),
+                        self.current_pos_end(),
+                        Some("add a '}' to close the map or add a map entry followed by a comma"),
+                    )));
+                }
+
+                parse_result.register_advancement();
+                self.advance();
+
+                self.skip_separators(&mut parse_result);
+            }
+
+            // a bare name before the ':' is the key itself, like {name: "maid"}
+            let key = if self.current_token_ref().token_type == TokenType::TT_IDENTIFIER
+                && self.peek(1).map(|token| &token.token_type) == Some(&TokenType::TT_COLON)
+            {
+                let mut token = self.current_token_copy();
+                token.token_type = TokenType::TT_STR;
+
+                parse_result.register_advancement();
+                self.advance();
+
+                Some(Box::new(AstNode::Strings(StringNode::new(token))))
+            } else {
+                parse_result.register(self.expr())
+            };
+
+            if parse_result.has_failed() {
+                return parse_result;
+            }
+
+            if self.current_token_ref().token_type != TokenType::TT_COLON {
+                return parse_result.failure(Some(StandardError::new(
+                    "expected ':'",
+                    self.current_pos_start(),
+                    self.current_pos_end(),
+                    Some("add a ':' between the key and its value"),
+                )));
+            }
+
+            parse_result.register_advancement();
+            self.advance();
+
+            self.skip_separators(&mut parse_result);
+
+            let value = parse_result.register(self.expr());
+
+            if parse_result.has_failed() {
+                return parse_result;
+            }
+
+            entry_nodes.push((key.unwrap(), value.unwrap()));
+
+            self.skip_separators(&mut parse_result);
+        }
+
+        let pos_end = self.current_token_ref().pos_end.clone();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        parse_result.success(Some(Box::new(AstNode::Map(MapNode::new(
+            &entry_nodes,
+            pos_start,
+            pos_end,
+        )))))
+    }
+
     pub fn if_expr(&mut self) -> ParseResult {
         let mut parse_result = ParseResult::new();
         let (if_parse_result, cases, else_case) = self.if_expr_cases("if");
+        parse_result.register(if_parse_result);
 
-        if if_parse_result.error.is_some() {
-            return if_parse_result;
+        if parse_result.has_failed() {
+            return parse_result;
         }
 
         parse_result.success(Some(Box::new(AstNode::If(IfNode::new(&cases, else_case)))))
@@ -290,7 +442,7 @@ impl Parser {
 
             let statements = parse_result.register(self.statements());
 
-            if parse_result.error.is_some() {
+            if parse_result.has_failed() {
                 return (parse_result, None);
             }
 
@@ -333,13 +485,12 @@ impl Parser {
             .matches(TokenType::TT_KEYWORD, "alsoif")
         {
             let (if_parse_result, mut new_cases, new_else_case) = self.if_expr_b();
+            parse_result.register(if_parse_result);
 
-            if if_parse_result.error.is_some() {
-                return (if_parse_result, Vec::new(), None);
+            if parse_result.has_failed() {
+                return (parse_result, Vec::new(), None);
             }
 
-            parse_result.register(if_parse_result);
-
             cases.append(&mut new_cases);
 
             if new_else_case.is_some() {
@@ -353,18 +504,58 @@ impl Parser {
             .matches(TokenType::TT_KEYWORD, "otherwise")
         {
             let (else_parse_result, new_else_case) = self.if_expr_c();
+            parse_result.register(else_parse_result);
 
-            if else_parse_result.error.is_some() {
-                return (else_parse_result, Vec::new(), None);
+            if parse_result.has_failed() {
+                return (parse_result, Vec::new(), None);
             }
-
-            parse_result.register(else_parse_result);
             else_case = new_else_case;
         }
 
         (parse_result, cases, else_case)
     }
 
+    // 'if a > b then a otherwise b' takes single expressions instead of blocks, so the branch
+    // taken gives its value back
+    pub fn if_expr_inline(
+        &mut self,
+        mut parse_result: ParseResult,
+        condition: Box<AstNode>,
+    ) -> (
+        ParseResult,
+        Vec<(Box<AstNode>, Box<AstNode>, bool)>,
+        Option<(Box<AstNode>, bool)>,
+    ) {
+        parse_result.register_advancement();
+        self.advance();
+
+        let expr = parse_result.register(self.expr());
+
+        if parse_result.has_failed() {
+            return (parse_result, Vec::new(), None);
+        }
+
+        let mut else_case: Option<(Box<AstNode>, bool)> = None;
+
+        if self
+            .current_token_ref()
+            .matches(TokenType::TT_KEYWORD, "otherwise")
+        {
+            parse_result.register_advancement();
+            self.advance();
+
+            let else_expr = parse_result.register(self.expr());
+
+            if parse_result.has_failed() {
+                return (parse_result, Vec::new(), None);
+            }
+
+            else_case = Some((else_expr.unwrap(), false));
+        }
+
+        (parse_result, vec![(condition, expr.unwrap(), false)], else_case)
+    }
+
     pub fn if_expr_cases(
         &mut self,
         keyword: &str,
@@ -398,10 +589,14 @@ impl Parser {
 
         let condition = parse_result.register(self.statement());
 
-        if parse_result.error.is_some() {
+        if parse_result.has_failed() {
             return (parse_result, Vec::new(), None);
         }
 
+        if self.current_token_ref().matches(TokenType::TT_KEYWORD, "then") {
+            return self.if_expr_inline(parse_result, condition.unwrap());
+        }
+
         self.skip_separators(&mut parse_result);
 
         if self.current_token_ref().token_type != TokenType::TT_LBRACKET {
@@ -422,7 +617,7 @@ impl Parser {
 
         let statements = parse_result.register(self.statements());
 
-        if parse_result.error.is_some() {
+        if parse_result.has_failed() {
             return (parse_result, Vec::new(), None);
         }
 
@@ -449,9 +644,10 @@ impl Parser {
         self.advance();
 
         let (if_parse_result, all_cases, else_clause) = self.if_expr_b_or_c();
+        parse_result.register(if_parse_result);
 
-        if if_parse_result.error.is_some() {
-            return (if_parse_result, Vec::new(), None);
+        if parse_result.has_failed() {
+            return (parse_result, Vec::new(), None);
         }
 
         else_case = else_clause;
@@ -460,6 +656,128 @@ impl Parser {
         (parse_result, cases, else_case)
     }
 
+    // match value { pattern -> body; ...; _ -> fallback }
+    pub fn match_expr(&mut self) -> ParseResult {
+        let mut parse_result = ParseResult::new();
+        let pos_start = self.current_pos_start();
+
+        if !self
+            .current_token_ref()
+            .matches(TokenType::TT_KEYWORD, "match")
+        {
+            return parse_result.failure(Some(StandardError::new(
+                "expected keyword",
+                self.current_pos_start(),
+                self.current_pos_end(),
+                Some("add the 'match' keyword to compare a value against several cases"),
+            )));
+        }
+
+        parse_result.register_advancement();
+        self.advance();
+
+        let subject = parse_result.register(self.expr());
+
+        if parse_result.has_failed() {
+            return parse_result;
+        }
+
+        self.skip_separators(&mut parse_result);
+
+        if self.current_token_ref().token_type != TokenType::TT_LBRACKET {
+            return parse_result.failure(Some(StandardError::new(
+                "expected '{'",
+                self.current_pos_start(),
+                self.current_pos_end(),
+                Some("add a '{' to list the cases"),
+            )));
+        }
+
+        parse_result.register_advancement();
+        self.advance();
+
+        let mut cases: Vec<(Box<AstNode>, Box<AstNode>)> = Vec::new();
+        let mut default_case: Option<Box<AstNode>> = None;
+
+        self.skip_separators(&mut parse_result);
+
+        while self.current_token_ref().token_type != TokenType::TT_RBRACKET {
+            if self.current_token_ref().token_type == TokenType::TT_EOF {
+                return parse_result.failure(Some(StandardError::new(
+                    "expected '}'",
+                    self.current_pos_start(),
+                    self.current_pos_end(),
+                    Some("add a '}' to close the cases"),
+                )));
+            }
+
+            if default_case.is_some() {
+                return parse_result.failure(Some(StandardError::new(
+                    "case after the '_' case",
+                    self.current_pos_start(),
+                    self.current_pos_end(),
+                    Some("the '_' case matches everything, move it to the end"),
+                )));
+            }
+
+            let is_default = self.current_token_ref().matches(TokenType::TT_IDENTIFIER, "_")
+                && self.peek(1).map(|token| &token.token_type) == Some(&TokenType::TT_ARROW);
+
+            let pattern = if is_default {
+                parse_result.register_advancement();
+                self.advance();
+
+                None
+            } else {
+                let pattern = parse_result.register(self.expr());
+
+                if parse_result.has_failed() {
+                    return parse_result;
+                }
+
+                pattern
+            };
+
+            if self.current_token_ref().token_type != TokenType::TT_ARROW {
+                return parse_result.failure(Some(StandardError::new(
+                    "expected '->'",
+                    self.current_pos_start(),
+                    self.current_pos_end(),
+                    Some("add a '->' followed by the value for this case"),
+                )));
+            }
+
+            parse_result.register_advancement();
+            self.advance();
+
+            let body = parse_result.register(self.expr());
+
+            if parse_result.has_failed() {
+                return parse_result;
+            }
+
+            match pattern {
+                Some(pattern) => cases.push((pattern, body.unwrap())),
+                None => default_case = body,
+            }
+
+            self.skip_separators(&mut parse_result);
+        }
+
+        let pos_end = self.current_pos_end();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        parse_result.success(Some(Box::new(AstNode::Match(MatchNode::new(
+            subject.unwrap(),
+            cases,
+            default_case,
+            Some(pos_start),
+            Some(pos_end),
+        )))))
+    }
+
     pub fn for_expr(&mut self) -> ParseResult {
         let mut parse_result = ParseResult::new();
 
@@ -491,6 +809,30 @@ impl Parser {
         parse_result.register_advancement();
         self.advance();
 
+        // `walk item in list { ... }` goes through a list's elements instead of a range
+        if self.current_token_ref().matches(TokenType::TT_KEYWORD, "in") {
+            parse_result.register_advancement();
+            self.advance();
+
+            let iterable = parse_result.register(self.expr());
+
+            if parse_result.has_failed() {
+                return parse_result;
+            }
+
+            let body = parse_result.register(self.for_body());
+
+            if parse_result.has_failed() {
+                return parse_result;
+            }
+
+            return parse_result.success(Some(Box::new(AstNode::ForIn(ForInNode::new(
+                var_name,
+                iterable.unwrap(),
+                body.unwrap(),
+            )))));
+        }
+
         if self.current_token_ref().token_type != TokenType::TT_EQ {
             return parse_result.failure(Some(StandardError::new(
                 "expected '='",
@@ -498,7 +840,7 @@ impl Parser {
                 self.current_pos_end(),
                 Some(
                     format!(
-                        "add an '=' to set the value of the variable '{}'",
+                        "add an '=' to set the value of the variable '{}', or 'in' to walk a list",
                         var_name.value.unwrap().clone()
                     )
                     .as_str(),
@@ -511,19 +853,22 @@ impl Parser {
 
         let start_value = parse_result.register(self.expr());
 
-        if parse_result.error.is_some() {
+        if parse_result.has_failed() {
             return parse_result;
         }
 
-        if !self
-            .current_token_ref()
-            .matches(TokenType::TT_KEYWORD, "through")
+        let inclusive = self.current_token_ref().matches(TokenType::TT_KEYWORD, "to");
+
+        if !inclusive
+            && !self
+                .current_token_ref()
+                .matches(TokenType::TT_KEYWORD, "through")
         {
             return parse_result.failure(Some(StandardError::new(
-                "expected 'through'",
+                "expected 'through' or 'to'",
                 self.current_pos_start(),
                 self.current_pos_end(),
-                Some("add the 'through' keyword to define a range 'n through n'"),
+                Some("use '0 through 3' for 0, 1, 2 without the end, or '0 to 3' for 0, 1, 2, 3"),
             )));
         }
 
@@ -532,7 +877,7 @@ impl Parser {
 
         let end_value = parse_result.register(self.expr());
 
-        if parse_result.error.is_some() {
+        if parse_result.has_failed() {
             return parse_result;
         }
 
@@ -559,13 +904,33 @@ impl Parser {
 
             step_value = parse_result.register(self.expr());
 
-            if parse_result.error.is_some() {
+            if parse_result.has_failed() {
                 return parse_result;
             }
         } else {
             step_value = None;
         }
 
+        let body = parse_result.register(self.for_body());
+
+        if parse_result.has_failed() {
+            return parse_result;
+        }
+
+        parse_result.success(Some(Box::new(AstNode::For(ForNode::new(
+            var_name,
+            start_value.unwrap(),
+            end_value.unwrap(),
+            step_value,
+            inclusive,
+            body.unwrap(),
+        )))))
+    }
+
+    // the body of a `walk` loop, from its '{' onwards
+    pub fn for_body(&mut self) -> ParseResult {
+        let mut parse_result = ParseResult::new();
+
         self.skip_separators(&mut parse_result);
 
         if self.current_token_ref().token_type != TokenType::TT_LBRACKET {
@@ -580,58 +945,39 @@ impl Parser {
         parse_result.register_advancement();
         self.advance();
 
-        if self.current_token_ref().token_type == TokenType::TT_NEWLINE {
-            parse_result.register_advancement();
-            self.advance();
-
-            let body = parse_result.register(self.statements());
-
-            if parse_result.error.is_some() {
-                return parse_result;
-            }
-
-            if self.current_token_ref().token_type != TokenType::TT_RBRACKET {
-                return parse_result.failure(Some(StandardError::new(
-                    "expected '}'",
-                    self.current_pos_start(),
-                    self.current_pos_end(),
-                    Some("add a '}' to close the body"),
-                )));
-            }
-
-            parse_result.register_advancement();
-            self.advance();
+        // one-line bodies like '{ serve(i); }' need their '}' consumed too
+        let body = parse_result.register(self.statements());
 
-            return parse_result.success(Some(Box::new(AstNode::For(ForNode::new(
-                var_name,
-                start_value.unwrap(),
-                end_value.unwrap(),
-                step_value,
-                body.unwrap(),
-            )))));
+        if parse_result.has_failed() {
+            return parse_result;
         }
 
-        let body = parse_result.register(self.statement());
-
-        if parse_result.error.is_some() {
-            return parse_result;
+        if self.current_token_ref().token_type != TokenType::TT_RBRACKET {
+            return parse_result.failure(Some(StandardError::new(
+                "expected '}'",
+                self.current_pos_start(),
+                self.current_pos_end(),
+                Some("add a '}' to close the body"),
+            )));
         }
 
-        parse_result.success(Some(Box::new(AstNode::For(ForNode::new(
-            var_name,
-            start_value.unwrap(),
-            end_value.unwrap(),
-            step_value,
-            body.unwrap(),
-        )))))
+        parse_result.register_advancement();
+        self.advance();
+
+        parse_result.success(body)
     }
 
+    // 'until' loops share the parsing, they only flip when the body runs
     pub fn while_expr(&mut self) -> ParseResult {
         let mut parse_result = ParseResult::new();
-
-        if !self
+        let is_until = self
             .current_token_ref()
-            .matches(TokenType::TT_KEYWORD, "while")
+            .matches(TokenType::TT_KEYWORD, "until");
+
+        if !is_until
+            && !self
+                .current_token_ref()
+                .matches(TokenType::TT_KEYWORD, "while")
         {
             return parse_result.failure(Some(StandardError::new(
                 "expected keyword",
@@ -646,7 +992,7 @@ impl Parser {
 
         let condition = parse_result.register(self.expr());
 
-        if parse_result.error.is_some() {
+        if parse_result.has_failed() {
             return parse_result;
         }
 
@@ -666,7 +1012,7 @@ impl Parser {
 
         let body = parse_result.register(self.statements());
 
-        if parse_result.error.is_some() {
+        if parse_result.has_failed() {
             return parse_result;
         }
 
@@ -682,10 +1028,13 @@ impl Parser {
         parse_result.register_advancement();
         self.advance();
 
-        parse_result.success(Some(Box::new(AstNode::While(WhileNode::new(
-            condition.unwrap(),
-            body.unwrap(),
-        )))))
+        let while_node = if is_until {
+            WhileNode::until(condition.unwrap(), body.unwrap())
+        } else {
+            WhileNode::new(condition.unwrap(), body.unwrap())
+        };
+
+        parse_result.success(Some(Box::new(AstNode::While(while_node))))
     }
 
     pub fn try_expr(&mut self) -> ParseResult {
@@ -722,7 +1071,7 @@ impl Parser {
 
         let try_body = parse_result.register(self.statements());
 
-        if parse_result.error.is_some() {
+        if parse_result.has_failed() {
             return parse_result;
         }
 
@@ -783,7 +1132,7 @@ impl Parser {
 
         let except_body = parse_result.register(self.statements());
 
-        if parse_result.error.is_some() {
+        if parse_result.has_failed() {
             return parse_result;
         }
 
@@ -826,21 +1175,187 @@ impl Parser {
 
         let import = parse_result.register(self.expr());
 
-        if parse_result.error.is_some() {
+        if parse_result.has_failed() {
             return parse_result;
         }
 
-        parse_result.register_advancement();
-        self.advance();
+        let mut alias_token: Option<Token> = None;
+
+        if self.current_token_ref().matches(TokenType::TT_KEYWORD, "as") {
+            parse_result.register_advancement();
+            self.advance();
+
+            if self.current_token_ref().token_type != TokenType::TT_IDENTIFIER {
+                return parse_result.failure(Some(StandardError::new(
+                    "expected object name",
+                    self.current_pos_start(),
+                    self.current_pos_end(),
+                    Some("add a name to bind the imported module to"),
+                )));
+            }
+
+            alias_token = Some(self.current_token_copy());
+
+            parse_result.register_advancement();
+            self.advance();
+        }
 
         parse_result.success(Some(Box::new(AstNode::Import(ImportNode::new(
             import.unwrap(),
+            alias_token,
+        )))))
+    }
+
+    // splits the template of an interpolated string into its literal parts and the expressions
+    // between '{' and '}', each parsed on its own. '{{' in the template is a literal brace
+    pub fn interp_string_expr(&mut self) -> ParseResult {
+        let mut parse_result = ParseResult::new();
+        let token = self.current_token_copy();
+        let template: Vec<char> = token.value.clone().unwrap_or_default().chars().collect();
+
+        let mut segments: Vec<Box<AstNode>> = Vec::new();
+        let mut literal = String::new();
+        let mut i = 0;
+
+        while i < template.len() {
+            if template[i] != '{' {
+                literal.push(template[i]);
+                i += 1;
+
+                continue;
+            }
+
+            if template.get(i + 1) == Some(&'{') {
+                literal.push('{');
+                i += 2;
+
+                continue;
+            }
+
+            // the lexer only lets closed expressions through
+            let end = i + template[i..].iter().position(|c| *c == '}').unwrap();
+            let expression: String = template[i + 1..end].iter().collect();
+
+            if !literal.is_empty() {
+                segments.push(self.interp_literal(&token, &literal));
+                literal.clear();
+            }
+
+            let node = parse_result.register(self.interp_expression(&token, expression));
+
+            if parse_result.has_failed() {
+                return parse_result;
+            }
+
+            segments.push(node.unwrap());
+
+            i = end + 1;
+        }
+
+        if !literal.is_empty() {
+            segments.push(self.interp_literal(&token, &literal));
+        }
+
+        parse_result.register_advancement();
+        self.advance();
+
+        parse_result.success(Some(Box::new(AstNode::InterpString(InterpStringNode::new(
+            &segments,
+            token.pos_start,
+            token.pos_end,
         )))))
     }
 
+    fn interp_literal(&self, token: &Token, literal: &str) -> Box<AstNode> {
+        Box::new(AstNode::Strings(StringNode::new(Token::new(
+            TokenType::TT_STR,
+            Some(literal.to_string()),
+            token.pos_start.clone(),
+            token.pos_end.clone(),
+        ))))
+    }
+
+    // lexes and parses the expression inside a '{...}' with a parser of its own. its tokens
+    // take the position of the whole string, since the expression has none in the file
+    fn interp_expression(&self, token: &Token, expression: String) -> ParseResult {
+        let pos_start = token.pos_start.clone().unwrap();
+        let pos_end = token.pos_end.clone().unwrap();
+
+        let mut lexer = Lexer::new(&pos_start.filename, expression);
+        let mut tokens = match lexer.make_tokens() {
+            Ok(tokens) => tokens,
+            Err(mut e) => {
+                *e.pos_start = pos_start;
+                *e.pos_end = pos_end;
+
+                return ParseResult::new().failure(Some(e));
+            }
+        };
+
+        for expression_token in tokens.iter_mut() {
+            expression_token.pos_start = Some(pos_start.clone());
+            expression_token.pos_end = Some(pos_end.clone());
+        }
+
+        let mut parser = Parser::new(&tokens);
+        parser.depth = self.depth;
+
+        let mut parse_result = parser.expr();
+
+        if !parse_result.has_failed()
+            && parser.current_token_copy().token_type != TokenType::TT_EOF
+        {
+            return parse_result.failure(Some(StandardError::new(
+                "expected '}' after the interpolated expression",
+                pos_start,
+                pos_end,
+                Some("put a single expression between '{' and '}', or write '{{' for a '{'"),
+            )));
+        }
+
+        parse_result
+    }
+
     pub fn expr(&mut self) -> ParseResult {
+        self.nested(Self::expr_body)
+    }
+
+    pub fn expr_body(&mut self) -> ParseResult {
         let mut parse_result = ParseResult::new();
 
+        // `name += value` and friends update an existing object in place
+        if self.current_token_ref().token_type == TokenType::TT_IDENTIFIER
+            && self.peek(1).is_some_and(|token| {
+                matches!(
+                    token.token_type,
+                    TokenType::TT_PLUS_EQ
+                        | TokenType::TT_MINUS_EQ
+                        | TokenType::TT_MUL_EQ
+                        | TokenType::TT_DIV_EQ
+                        | TokenType::TT_POW_EQ
+                        | TokenType::TT_MOD_EQ
+                )
+            })
+        {
+            let var_name = self.current_token_copy();
+            parse_result.register_advancement();
+            self.advance();
+
+            let op_token = self.current_token_copy();
+            parse_result.register_advancement();
+            self.advance();
+
+            let expr = parse_result.register(self.expr());
+
+            if parse_result.has_failed() {
+                return parse_result;
+            }
+
+            return parse_result.success(Some(Box::new(AstNode::CompoundAssign(
+                CompoundAssignNode::new(var_name, op_token, expr.unwrap()),
+            ))));
+        }
+
         if self
             .current_token_ref()
             .matches(TokenType::TT_KEYWORD, "obj")
@@ -862,6 +1377,48 @@ impl Parser {
             parse_result.register_advancement();
             self.advance();
 
+            // `obj name.field = value` changes one property of a map or struct
+            if self.current_token_ref().token_type == TokenType::TT_DOT {
+                parse_result.register_advancement();
+                self.advance();
+
+                let property = self.current_token_copy();
+
+                if property.token_type != TokenType::TT_IDENTIFIER {
+                    return parse_result.failure(Some(StandardError::new(
+                        "expected property name after '.'",
+                        self.current_pos_start(),
+                        self.current_pos_end(),
+                        Some("write the name of the property to set after the '.', like 'point.x'"),
+                    )));
+                }
+
+                parse_result.register_advancement();
+                self.advance();
+
+                if self.current_token_ref().token_type != TokenType::TT_EQ {
+                    return parse_result.failure(Some(StandardError::new(
+                        "expected '='",
+                        self.current_pos_start(),
+                        self.current_pos_end(),
+                        Some("add an '=' to set the value of the property"),
+                    )));
+                }
+
+                parse_result.register_advancement();
+                self.advance();
+
+                let expr = parse_result.register(self.expr());
+
+                if parse_result.has_failed() {
+                    return parse_result;
+                }
+
+                return parse_result.success(Some(Box::new(AstNode::PropertyAssign(
+                    PropertyAssignNode::new(var_name, property, expr.unwrap()),
+                ))));
+            }
+
             if self.current_token_copy().token_type != TokenType::TT_EQ {
                 return parse_result.failure(Some(StandardError::new(
                     "expected '='",
@@ -882,7 +1439,7 @@ impl Parser {
 
             let expr = parse_result.register(self.expr());
 
-            if parse_result.error.is_some() {
+            if parse_result.has_failed() {
                 return parse_result;
             }
 
@@ -930,7 +1487,7 @@ impl Parser {
 
             let expr = parse_result.register(self.expr());
 
-            if parse_result.error.is_some() {
+            if parse_result.has_failed() {
                 return parse_result;
             }
 
@@ -948,7 +1505,7 @@ impl Parser {
             None,
         ));
 
-        if parse_result.error.is_some() {
+        if parse_result.has_failed() {
             return parse_result.failure(Some(StandardError::new(
                 "expected keyword, object, function, expression",
                 self.current_pos_start(),
@@ -971,11 +1528,26 @@ impl Parser {
             parse_result.register_advancement();
             self.advance();
 
-            let expr = parse_result.try_register(self.expr());
+            // a bare 'give' returns nothing, so only parse a value if one follows
+            let expr = if matches!(
+                self.peek(0).map(|token| &token.token_type),
+                Some(
+                    TokenType::TT_NEWLINE
+                        | TokenType::TT_SEMI
+                        | TokenType::TT_RBRACKET
+                        | TokenType::TT_EOF
+                )
+            ) {
+                None
+            } else {
+                let expr = parse_result.register(self.expr());
 
-            if expr.is_none() {
-                self.reverse(parse_result.to_reverse_count);
-            }
+                if parse_result.has_failed() {
+                    return parse_result;
+                }
+
+                expr
+            };
 
             return parse_result.success(Some(Box::new(AstNode::Return(ReturnNode::new(
                 expr,
@@ -1004,11 +1576,58 @@ impl Parser {
                 Some(pos_start),
                 Some(self.current_pos_start()),
             )))));
+        } else if self
+            .current_token_ref()
+            .matches(TokenType::TT_KEYWORD, "export")
+        {
+            let doc = self.current_token_ref().doc.as_deref().map(String::from);
+
+            parse_result.register_advancement();
+            self.advance();
+
+            let node = parse_result.register(self.expr());
+
+            if parse_result.has_failed() {
+                return parse_result;
+            }
+
+            let mut node = node.unwrap();
+
+            // the comment sits above 'export' rather than 'func'
+            if let AstNode::FunctionDefinition(function) = node.as_mut() {
+                function.doc = function.doc.take().or(doc);
+            }
+
+            let export_name = match node.as_ref() {
+                AstNode::FunctionDefinition(node) => node
+                    .var_name_token
+                    .as_ref()
+                    .and_then(|token| token.value.clone()),
+                AstNode::VariableAssign(node) => node.var_name_token.value.clone(),
+                AstNode::ConstAssign(node) => node.const_name_token.value.clone(),
+                AstNode::StructDef(node) => node.name_token.value.clone(),
+                _ => None,
+            };
+
+            if export_name.is_none() {
+                return parse_result.failure(Some(StandardError::new(
+                    "expected a named function or object",
+                    pos_start,
+                    self.current_pos_end(),
+                    Some("add a 'func', 'struct', 'obj' or 'stay' definition after 'export'"),
+                )));
+            }
+
+            return parse_result.success(Some(Box::new(AstNode::Export(ExportNode::new(
+                node,
+                export_name.unwrap(),
+                Some(pos_start),
+            )))));
         }
 
         let expr = parse_result.register(self.expr());
 
-        if parse_result.error.is_some() {
+        if parse_result.has_failed() {
             return parse_result.failure(Some(StandardError::new(
                 "expected keyword, object, function, expression",
                 pos_start,
@@ -1025,45 +1644,29 @@ impl Parser {
         let mut statements: Vec<Box<AstNode>> = Vec::new();
         let pos_start = self.current_pos_start();
 
-        while self.current_token_ref().token_type == TokenType::TT_NEWLINE {
-            parse_result.register_advancement();
-            self.advance();
-        }
+        self.skip_separators(&mut parse_result);
 
         if self.current_token_ref().token_type == TokenType::TT_EOF {
             return parse_result.success(Some(Box::new(AstNode::List(ListNode::new(
                 &[],
+                true,
                 Some(pos_start),
                 Some(self.current_pos_end()),
             )))));
         }
 
-        let statement = parse_result.register(self.statement());
-
-        if parse_result.error.is_some() {
-            return parse_result;
-        }
-
-        statements.push(statement.unwrap());
-
-         // soft enforce either a newline, a '}', or EOF.
-         if !matches!(
-             self.current_token_ref().token_type,
-             TokenType::TT_NEWLINE | TokenType::TT_RBRACKET | TokenType::TT_SEMI | TokenType::TT_EOF){
-                return parse_result.failure(Some(StandardError::new(
-                    "expected newline or statement separator",
-                    self.current_pos_start(),
-                    self.current_pos_end(),
-                    Some("add a newline or semicolon between statements"),
-                )));
-            }
+        statements.extend(self.statement_or_recover(&mut parse_result));
 
         let mut more_statements = true;
 
         loop {
             let mut newline_count: usize = 0;
 
-            while self.current_token_ref().token_type == TokenType::TT_NEWLINE {
+            // a semicolon ends a statement just like a newline does
+            while matches!(
+                self.current_token_ref().token_type,
+                TokenType::TT_NEWLINE | TokenType::TT_SEMI
+            ) {
                 parse_result.register_advancement();
                 self.advance();
 
@@ -1086,35 +1689,86 @@ impl Parser {
                 break;
             }
 
-            let statement = parse_result.register(self.statement());
-
-            if parse_result.error.is_some() {
-                return parse_result;
-            }
-
-            statements.push(statement.unwrap());
+            statements.extend(self.statement_or_recover(&mut parse_result));
         }
 
         parse_result.success(Some(Box::new(AstNode::List(ListNode::new(
             &statements,
+            true,
             Some(pos_start),
             Some(self.current_pos_end()),
         )))))
     }
 
+    pub fn statement_or_recover(
+        &mut self,
+        parse_result: &mut ParseResult,
+    ) -> Option<Box<AstNode>> {
+        let statement = parse_result.register(self.statement());
+
+        if parse_result.has_failed() {
+            self.synchronize(parse_result);
+
+            return None;
+        }
+
+        // soft enforce either a newline, a '}', or EOF.
+        if !matches!(
+            self.current_token_ref().token_type,
+            TokenType::TT_NEWLINE | TokenType::TT_RBRACKET | TokenType::TT_SEMI | TokenType::TT_EOF
+        ) {
+            parse_result.failure(Some(StandardError::new(
+                "expected newline or statement separator",
+                self.current_pos_start(),
+                self.current_pos_end(),
+                Some("add a newline or semicolon between statements"),
+            )));
+
+            self.synchronize(parse_result);
+        }
+
+        statement
+    }
+
     pub fn call(&mut self) -> ParseResult {
         let mut parse_result = ParseResult::new();
-        let atom = parse_result.register(self.atom());
+        let mut atom = parse_result.register(self.atom());
 
-        if parse_result.error.is_some() {
+        if parse_result.has_failed() {
             return parse_result;
         }
 
+        // `a.b.c` reads properties one after another, each from the map before it
+        while self.current_token_ref().token_type == TokenType::TT_DOT {
+            parse_result.register_advancement();
+            self.advance();
+
+            let property = self.current_token_copy();
+
+            if property.token_type != TokenType::TT_IDENTIFIER {
+                return parse_result.failure(Some(StandardError::new(
+                    "expected property name after '.'",
+                    self.current_pos_start(),
+                    self.current_pos_end(),
+                    Some("write the name of a map key after the '.', like 'point.x'"),
+                )));
+            }
+
+            parse_result.register_advancement();
+            self.advance();
+
+            atom = Some(Box::new(AstNode::PropertyAccess(PropertyAccessNode::new(
+                atom.unwrap(),
+                property.value.unwrap_or_default(),
+                property.pos_end,
+            ))));
+        }
+
         if self.current_token_ref().token_type == TokenType::TT_LPAREN {
             parse_result.register_advancement();
             self.advance();
 
-            let mut arg_nodes: Vec<Box<AstNode>> = Vec::new();
+            let mut arg_nodes: Vec<AstNode> = Vec::new();
 
             if self.current_token_ref().token_type == TokenType::TT_RPAREN {
                 parse_result.register_advancement();
@@ -1122,7 +1776,7 @@ impl Parser {
             } else {
                 let expr = parse_result.register(self.expr());
 
-                if parse_result.error.is_some() {
+                if parse_result.has_failed() {
                     return parse_result.failure(Some(StandardError::new(
                         "expected keyword, object, function, expression",
                         self.current_pos_start(),
@@ -1131,17 +1785,19 @@ impl Parser {
                     )));
                 }
 
-                arg_nodes.push(expr.unwrap());
+                arg_nodes.push(*expr.unwrap());
 
                 while self.current_token_ref().token_type == TokenType::TT_COMMA {
                     parse_result.register_advancement();
                     self.advance();
 
-                    arg_nodes.push(parse_result.register(self.expr()).unwrap());
+                    let expr = parse_result.register(self.expr());
 
-                    if parse_result.error.is_some() {
+                    if parse_result.has_failed() {
                         return parse_result;
                     }
+
+                    arg_nodes.push(*expr.unwrap());
                 }
 
                 if self.current_token_ref().token_type != TokenType::TT_RPAREN {
@@ -1180,6 +1836,26 @@ impl Parser {
             self.advance();
 
             return parse_result.success(Some(Box::new(AstNode::Strings(StringNode::new(token)))));
+        } else if token.token_type == TokenType::TT_INTERP_STR {
+            let expr = parse_result.register(self.interp_string_expr());
+
+            if parse_result.has_failed() {
+                return parse_result;
+            }
+
+            return parse_result.success(expr);
+        } else if token.matches(TokenType::TT_KEYWORD, "true")
+            || token.matches(TokenType::TT_KEYWORD, "false")
+        {
+            parse_result.register_advancement();
+            self.advance();
+
+            return parse_result.success(Some(Box::new(AstNode::Bool(BoolNode::new(token)))));
+        } else if token.matches(TokenType::TT_KEYWORD, "null") {
+            parse_result.register_advancement();
+            self.advance();
+
+            return parse_result.success(Some(Box::new(AstNode::Null(NullNode::new(token)))));
         } else if token.token_type == TokenType::TT_IDENTIFIER {
             parse_result.register_advancement();
             self.advance();
@@ -1192,7 +1868,7 @@ impl Parser {
             self.advance();
             let expr = parse_result.register(self.expr());
 
-            if parse_result.error.is_some() {
+            if parse_result.has_failed() {
                 return parse_result;
             }
 
@@ -1212,7 +1888,15 @@ impl Parser {
         } else if token.token_type == TokenType::TT_LSQUARE {
             let expr = parse_result.register(self.list_expr());
 
-            if parse_result.error.is_some() {
+            if parse_result.has_failed() {
+                return parse_result;
+            }
+
+            return parse_result.success(expr);
+        } else if token.token_type == TokenType::TT_LBRACKET {
+            let expr = parse_result.register(self.map_expr());
+
+            if parse_result.has_failed() {
                 return parse_result;
             }
 
@@ -1220,7 +1904,15 @@ impl Parser {
         } else if token.matches(TokenType::TT_KEYWORD, "if") {
             let expr = parse_result.register(self.if_expr());
 
-            if parse_result.error.is_some() {
+            if parse_result.has_failed() {
+                return parse_result;
+            }
+
+            return parse_result.success(expr);
+        } else if token.matches(TokenType::TT_KEYWORD, "match") {
+            let expr = parse_result.register(self.match_expr());
+
+            if parse_result.has_failed() {
                 return parse_result;
             }
 
@@ -1228,15 +1920,17 @@ impl Parser {
         } else if token.matches(TokenType::TT_KEYWORD, "walk") {
             let expr = parse_result.register(self.for_expr());
 
-            if parse_result.error.is_some() {
+            if parse_result.has_failed() {
                 return parse_result;
             }
 
             return parse_result.success(expr);
-        } else if token.matches(TokenType::TT_KEYWORD, "while") {
+        } else if token.matches(TokenType::TT_KEYWORD, "while")
+            || token.matches(TokenType::TT_KEYWORD, "until")
+        {
             let expr = parse_result.register(self.while_expr());
 
-            if parse_result.error.is_some() {
+            if parse_result.has_failed() {
                 return parse_result;
             }
 
@@ -1244,7 +1938,7 @@ impl Parser {
         } else if token.matches(TokenType::TT_KEYWORD, "unsafe") {
             let expr = parse_result.register(self.try_expr());
 
-            if parse_result.error.is_some() {
+            if parse_result.has_failed() {
                 return parse_result;
             }
 
@@ -1252,15 +1946,23 @@ impl Parser {
         } else if token.matches(TokenType::TT_KEYWORD, "func") {
             let func_def = parse_result.register(self.func_definition());
 
-            if parse_result.error.is_some() {
+            if parse_result.has_failed() {
                 return parse_result;
             }
 
             return parse_result.success(func_def);
+        } else if token.matches(TokenType::TT_KEYWORD, "struct") {
+            let struct_def = parse_result.register(self.struct_definition());
+
+            if parse_result.has_failed() {
+                return parse_result;
+            }
+
+            return parse_result.success(struct_def);
         } else if token.matches(TokenType::TT_KEYWORD, "fetch") {
             let import_expr = parse_result.register(self.import_expr());
 
-            if parse_result.error.is_some() {
+            if parse_result.has_failed() {
                 return parse_result;
             }
 
@@ -1286,9 +1988,9 @@ impl Parser {
         if [TokenType::TT_PLUS, TokenType::TT_MINUS].contains(&token.token_type) {
             parse_result.register_advancement();
             self.advance();
-            let factor = parse_result.register(self.factor());
+            let factor = parse_result.register(self.nested(Self::factor));
 
-            if parse_result.error.is_some() {
+            if parse_result.has_failed() {
                 return parse_result;
             }
 
@@ -1314,6 +2016,7 @@ impl Parser {
 
     pub fn func_definition(&mut self) -> ParseResult {
         let mut parse_result = ParseResult::new();
+        let doc = self.current_token_ref().doc.as_deref().map(String::from);
 
         if !self
             .current_token_ref()
@@ -1361,19 +2064,38 @@ impl Parser {
         parse_result.register_advancement();
         self.advance();
 
-        let mut arg_name_tokens: Vec<Token> = Vec::new();
+        let mut arg_name_tokens: Vec<DefaultParam> = Vec::new();
+        let mut is_variadic = false;
+        let param_starts = [TokenType::TT_IDENTIFIER, TokenType::TT_ELLIPSIS];
+
+        if param_starts.contains(&self.current_token_ref().token_type) {
+            loop {
+                let (param_result, param) =
+                    if self.current_token_ref().token_type == TokenType::TT_ELLIPSIS {
+                        is_variadic = true;
+                        self.func_rest_param()
+                    } else {
+                        let after_default =
+                            arg_name_tokens.iter().any(|arg| arg.default_node.is_some());
+                        self.func_param(after_default)
+                    };
+                parse_result.register(param_result);
+
+                if parse_result.has_failed() {
+                    return parse_result;
+                }
 
-        if self.current_token_ref().token_type == TokenType::TT_IDENTIFIER {
-            arg_name_tokens.push(self.current_token_copy());
+                arg_name_tokens.extend(param);
 
-            parse_result.register_advancement();
-            self.advance();
+                // the rest argument takes whatever is left, so it has to come last
+                if is_variadic || self.current_token_ref().token_type != TokenType::TT_COMMA {
+                    break;
+                }
 
-            while self.current_token_ref().token_type == TokenType::TT_COMMA {
                 parse_result.register_advancement();
                 self.advance();
 
-                if self.current_token_ref().token_type != TokenType::TT_IDENTIFIER {
+                if !param_starts.contains(&self.current_token_ref().token_type) {
                     return parse_result.failure(Some(StandardError::new(
                         "expected identifier",
                         self.current_pos_start(),
@@ -1381,11 +2103,15 @@ impl Parser {
                         Some("add a name for the function arguments like 'name'"),
                     )));
                 }
+            }
 
-                arg_name_tokens.push(self.current_token_copy());
-
-                parse_result.register_advancement();
-                self.advance();
+            if is_variadic && self.current_token_ref().token_type != TokenType::TT_RPAREN {
+                return parse_result.failure(Some(StandardError::new(
+                    "expected ')' after the rest argument",
+                    self.current_pos_start(),
+                    self.current_pos_end(),
+                    Some("the '...' argument has to be the last one, close the arguments with ')'"),
+                )));
             }
 
             if self.current_token_ref().token_type != TokenType::TT_RPAREN {
@@ -1424,7 +2150,7 @@ impl Parser {
 
         let body = parse_result.register(self.statements());
 
-        if parse_result.error.is_some() {
+        if parse_result.has_failed() {
             return parse_result;
         }
 
@@ -1441,10 +2167,179 @@ impl Parser {
         self.advance();
 
         parse_result.success(Some(Box::new(AstNode::FunctionDefinition(
-            FunctionDefinitionNode::new(var_name_token, &arg_name_tokens, body.unwrap(), false),
+            FunctionDefinitionNode::new(
+                var_name_token,
+                &arg_name_tokens,
+                is_variadic,
+                body.unwrap(),
+                false,
+                doc,
+            ),
         ))))
     }
 
+    pub fn struct_definition(&mut self) -> ParseResult {
+        let mut parse_result = ParseResult::new();
+        let pos_start = self.current_token_ref().pos_start.clone();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        if self.current_token_ref().token_type != TokenType::TT_IDENTIFIER {
+            return parse_result.failure(Some(StandardError::new(
+                "expected identifier",
+                self.current_pos_start(),
+                self.current_pos_end(),
+                Some("add a name for this struct like 'Point'"),
+            )));
+        }
+
+        let name_token = self.current_token_copy();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        if self.current_token_ref().token_type != TokenType::TT_LBRACKET {
+            return parse_result.failure(Some(StandardError::new(
+                "expected '{'",
+                self.current_pos_start(),
+                self.current_pos_end(),
+                Some("list the fields of the struct between braces, like 'struct Point { x, y }'"),
+            )));
+        }
+
+        parse_result.register_advancement();
+        self.advance();
+
+        self.skip_separators(&mut parse_result);
+
+        let mut field_name_tokens: Vec<Token> = Vec::new();
+
+        while self.current_token_ref().token_type != TokenType::TT_RBRACKET {
+            if !field_name_tokens.is_empty() {
+                if self.current_token_ref().token_type != TokenType::TT_COMMA {
+                    return parse_result.failure(Some(StandardError::new(
+                        "expected ',' or '}'",
+                        self.current_pos_start(),
+                        self.current_pos_end(),
+                        Some("add a ',' before the next field or close the struct with a '}'"),
+                    )));
+                }
+
+                parse_result.register_advancement();
+                self.advance();
+
+                self.skip_separators(&mut parse_result);
+            }
+
+            let field_token = self.current_token_copy();
+
+            if field_token.token_type != TokenType::TT_IDENTIFIER {
+                return parse_result.failure(Some(StandardError::new(
+                    "expected field name",
+                    self.current_pos_start(),
+                    self.current_pos_end(),
+                    Some("fields are plain names, like the 'x' in 'struct Point { x, y }'"),
+                )));
+            }
+
+            if field_name_tokens.iter().any(|field| field.value == field_token.value) {
+                return parse_result.failure(Some(StandardError::new(
+                    format!("duplicate field '{}'", field_token.value.unwrap_or_default())
+                        .as_str(),
+                    self.current_pos_start(),
+                    self.current_pos_end(),
+                    Some("every field of a struct needs its own name"),
+                )));
+            }
+
+            field_name_tokens.push(field_token);
+
+            parse_result.register_advancement();
+            self.advance();
+
+            self.skip_separators(&mut parse_result);
+        }
+
+        let pos_end = self.current_token_ref().pos_end.clone();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        parse_result.success(Some(Box::new(AstNode::StructDef(StructDefNode::new(
+            name_token,
+            field_name_tokens,
+            pos_start,
+            pos_end,
+        )))))
+    }
+
+    // a single function argument, either 'name' or 'name = default'. once an argument has a
+    // default value, all the ones after it need one too
+    pub fn func_param(&mut self, after_default: bool) -> (ParseResult, Option<DefaultParam>) {
+        let mut parse_result = ParseResult::new();
+        let name_token = self.current_token_copy();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        if self.current_token_ref().token_type != TokenType::TT_EQ {
+            if after_default {
+                return (
+                    parse_result.failure(Some(StandardError::new(
+                        "required argument after one with a default value",
+                        name_token.pos_start.clone().unwrap(),
+                        name_token.pos_end.clone().unwrap(),
+                        Some("give this argument a default value or move it before the others"),
+                    ))),
+                    None,
+                );
+            }
+
+            return (parse_result, Some(DefaultParam::new(name_token, None)));
+        }
+
+        parse_result.register_advancement();
+        self.advance();
+
+        let default_node = parse_result.register(self.expr());
+
+        if parse_result.has_failed() {
+            return (parse_result, None);
+        }
+
+        (parse_result, Some(DefaultParam::new(name_token, default_node)))
+    }
+
+    // the '...name' argument that collects any extra arguments of a call into a list
+    pub fn func_rest_param(&mut self) -> (ParseResult, Option<DefaultParam>) {
+        let mut parse_result = ParseResult::new();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        if self.current_token_ref().token_type != TokenType::TT_IDENTIFIER {
+            return (
+                parse_result.failure(Some(StandardError::new(
+                    "expected identifier after '...'",
+                    self.current_pos_start(),
+                    self.current_pos_end(),
+                    Some("add a name for the list of extra arguments like '...rest'"),
+                ))),
+                None,
+            );
+        }
+
+        let name_token = self.current_token_copy();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        (parse_result, Some(DefaultParam::new(name_token, None)))
+    }
+
+    // a chain like 1 + 1 + 1 is built in a loop, each operator taking the ones before it as its
+    // left side, so however long it is it's no deeper to parse than a single operator
     pub fn binary_operator(
         &mut self,
         func_a: &str,
@@ -1456,6 +2351,7 @@ impl Parser {
         let mut parse_result = ParseResult::new();
         let mut left = parse_result.register(match func_a {
             "comparison_expr" => self.comparison_expr(),
+            "coalesce_expr" => self.coalesce_expr(),
             "arithmetic_expr" => self.arithmetic_expr(),
             "term" => self.term(),
             "factor" => self.factor(),
@@ -1463,7 +2359,7 @@ impl Parser {
             _ => panic!("CRITICAL ERROR: MAID COULD NOT FIND EXPRESSION IN BINARY OPERATOR"),
         });
 
-        if parse_result.error.is_some() {
+        if parse_result.has_failed() {
             return parse_result;
         }
 
@@ -1477,11 +2373,30 @@ impl Parser {
                 .as_str(),
         )) || ops.contains(&(self.current_token.clone().unwrap().token_type, ""))
         {
-            let op_token = self.current_token.clone().unwrap().clone();
+            let mut op_token = self.current_token.clone().unwrap().clone();
             parse_result.register_advancement();
             self.advance();
+
+            // after a value 'not' can only start 'not in', which becomes one operator
+            if op_token.matches(TokenType::TT_KEYWORD, "not") {
+                if !self.current_token_ref().matches(TokenType::TT_KEYWORD, "in") {
+                    return parse_result.failure(Some(StandardError::new(
+                        "expected 'in' after 'not'",
+                        self.current_pos_start(),
+                        self.current_pos_end(),
+                        Some("use 'not in' to check that a value isn't in a string, list or map"),
+                    )));
+                }
+
+                op_token.value = Some("not in".to_string());
+                op_token.pos_end = self.current_token_ref().pos_end.clone();
+                parse_result.register_advancement();
+                self.advance();
+            }
+
             let right = parse_result.register(match func_b {
                 "comparison_expr" => self.comparison_expr(),
+                "coalesce_expr" => self.coalesce_expr(),
                 "arithmetic_expr" => self.arithmetic_expr(),
                 "term" => self.term(),
                 "factor" => self.factor(),
@@ -1489,15 +2404,29 @@ impl Parser {
                 _ => panic!("CRITICAL ERROR: MAID COULD NOT FIND EXPRESSION IN BINARY OPERATOR"),
             });
 
-            if parse_result.error.is_some() {
+            if parse_result.has_failed() {
                 return parse_result;
             }
 
-            left = Some(Box::new(AstNode::BinaryOperator(BinaryOperatorNode::new(
-                left.unwrap().clone(),
-                op_token,
-                right.unwrap(),
-            ))));
+            left = Some(Box::new(match *right.unwrap() {
+                // `value ^ "name"(args)` calls a method, handing `value` over as its first argument
+                AstNode::Call(call)
+                    if op_token.token_type == TokenType::TT_POW
+                        && matches!(call.node_to_call.as_ref(), AstNode::Strings(_)) =>
+                {
+                    AstNode::MethodCall(MethodCallNode::new(
+                        left.unwrap(),
+                        call.node_to_call,
+                        call.arg_nodes,
+                        call.pos_end,
+                    ))
+                }
+                right => AstNode::BinaryOperator(BinaryOperatorNode::new(
+                    left.unwrap(),
+                    op_token,
+                    Box::new(right),
+                )),
+            }));
         }
 
         parse_result.success(left)
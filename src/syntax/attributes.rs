@@ -8,17 +8,67 @@ pub const KEYWORDS: &[&str] = &[
     "or",
     "not",
     "if",
+    "then",
     "alsoif",
     "otherwise",
+    "match",
     "walk",
     "through",
+    "to",
+    "in",
     "step",
     "while",
+    "until",
     "unsafe",
     "safe",
     "func",
+    "struct",
     "fetch",
+    "as",
+    "export",
     "give",
     "next",
     "leave",
+    "true",
+    "false",
+    "null",
 ];
+
+// names of the functions implemented in `BuiltInFunction::execute`
+pub const BUILT_INS: &[&str] = &[
+    "serve", "process", "sweep", "stash", "tostring", "tonumber", "length", "uhoh", "type", "run",
+    "_env", "rest", "inline", "servef", "serve_table", "progress", "apply", "split", "join",
+    "trim", "trimstart", "trimend", "replace", "contains", "slice", "sqrt", "abs", "floor", "ceil",
+    "round", "random", "capitalize", "title_case", "reverse", "push", "pop", "get", "map", "filter",
+    "reduce", "sort", "exit", "benchmark", "assert", "jsonparse", "jsonstring", "sin", "cos",
+    "tan", "log", "log2", "exp",
+];
+
+// numbers set in the global table next to the built-ins. they're plain names rather than
+// constants, so a script can still use 'e' for something of its own
+pub const BUILT_IN_NUMBERS: &[(&str, f64)] =
+    &[("pi", std::f64::consts::PI), ("e", std::f64::consts::E)];
+
+// set in every script and module context, never copied over by an import
+pub const FILE_SYMBOLS: [&str; 2] = ["__file__", "__dir__"];
+
+// deepest expression/node nesting allowed before bailing out with an error
+// instead of overflowing the native stack
+pub const MAX_NESTING_DEPTH: usize = 1000;
+
+// most function calls allowed inside each other before bailing out with an error. every call
+// takes a few dozen kilobytes of native stack in a debug build, so a run this deep needs a
+// thread with STACK_SIZE of it
+pub const MAX_CALL_DEPTH: usize = 2000;
+
+// stack (in bytes) that holds MAX_CALL_DEPTH calls with MAX_NESTING_DEPTH levels of nesting
+// on top, which is what the command line runs maid on
+pub const STACK_SIZE: usize = 512 * 1024 * 1024;
+
+// longest string (in bytes) that multiplying a string may build when no limit is given,
+// about 100MB
+pub const DEFAULT_MAX_REPEAT_LENGTH: usize = 100_000_000;
+
+// largest integer literal allowed, 2^53. a number (f64) holds every integer up to it exactly,
+// past it some are rounded to a neighbour (2^53 + 1 can't be stored)
+pub const MAX_EXACT_INTEGER: u64 = 9_007_199_254_740_992;
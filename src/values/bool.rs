@@ -0,0 +1,55 @@
+use std::{cell::RefCell, rc::Weak};
+
+use crate::{
+    errors::standard_error::StandardError, interpreting::context::Context,
+    lexing::position::Position, values::value::Value,
+};
+
+#[derive(Debug, Clone)]
+pub struct Bool {
+    pub value: bool,
+    pub context: Option<Weak<RefCell<Context>>>,
+    pub pos_start: Option<Position>,
+    pub pos_end: Option<Position>,
+}
+
+impl Bool {
+    pub fn new(value: bool) -> Self {
+        Self {
+            value,
+            context: None,
+            pos_start: None,
+            pos_end: None,
+        }
+    }
+
+    pub fn from(value: bool) -> Value {
+        Value::BoolValue(Bool::new(value))
+    }
+
+    pub fn perform_operation(&self, operator: &str, other: Value) -> Result<Value, StandardError> {
+        let result = match (operator, &other) {
+            ("==", Value::BoolValue(right)) => self.value == right.value,
+            ("!=", Value::BoolValue(right)) => self.value != right.value,
+            _ => return Err(self.illegal_operation(Some(other))),
+        };
+
+        Ok(Bool::from(result).set_weak_context(self.context.clone()))
+    }
+
+    pub fn illegal_operation(&self, other: Option<Value>) -> StandardError {
+        StandardError::new(
+            "operation not supported by the boolean type",
+            self.pos_start.as_ref().unwrap().clone(),
+            // unary operators hand over a placeholder value that has no position
+            other
+                .and_then(|other| other.position_end())
+                .unwrap_or_else(|| self.pos_end.as_ref().unwrap().clone()),
+            Some("booleans only support 'and', 'or', 'not', '==' and '!='"),
+        )
+    }
+
+    pub fn as_string(&self) -> String {
+        self.value.to_string()
+    }
+}
@@ -6,21 +6,143 @@ use crate::{
     },
     lexing::{lexer::Lexer, position::Position},
     parsing::parser::Parser,
-    values::{number::Number, string::Str, value::Value},
+    values::{
+        bool::Bool, list::List, map::Map, null::Null, number::Number, string::Str, value::Value,
+    },
 };
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
+    collections::{HashMap, hash_map::RandomState},
     env, fs,
+    hash::{BuildHasher, Hasher},
     io::{Write, stdin, stdout},
+    process,
     thread,
-    time::Duration,
-    rc::Rc,
+    time::{Duration, Instant},
+    rc::{Rc, Weak},
 };
 
+// number of characters between the brackets of a progress bar
+const PROGRESS_BAR_WIDTH: usize = 20;
+
+thread_local! {
+    // state of the xorshift generator behind 'random', seeded differently on every run
+    static RANDOM_STATE: Cell<u64> = Cell::new(RandomState::new().build_hasher().finish() | 1);
+}
+
+// a number in [0, 1), good enough for games and simulations but not for anything secret
+fn next_random() -> f64 {
+    let mut x = RANDOM_STATE.get();
+    x ^= x >> 12;
+    x ^= x << 25;
+    x ^= x >> 27;
+    RANDOM_STATE.set(x);
+
+    (x.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 11) as f64 / (1u64 << 53) as f64
+}
+
+// JSON objects become maps and arrays lists, the rest map onto the value of the same kind
+fn json_to_value(json: serde_json::Value) -> Value {
+    match json {
+        serde_json::Value::Null => Value::NullValue(Null::new()),
+        serde_json::Value::Bool(value) => Bool::from(value),
+        serde_json::Value::Number(number) => Number::from(number.as_f64().unwrap_or(f64::NAN)),
+        serde_json::Value::String(string) => Str::from(&string),
+        serde_json::Value::Array(elements) => {
+            Value::ListValue(List::new(elements.into_iter().map(json_to_value).collect()))
+        }
+        serde_json::Value::Object(entries) => Map::from(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key, json_to_value(value)))
+                .collect(),
+        ),
+    }
+}
+
+// the most elements any array in the JSON holds, nested ones included
+fn longest_json_array(json: &serde_json::Value) -> usize {
+    match json {
+        serde_json::Value::Array(elements) => {
+            elements.iter().map(longest_json_array).fold(elements.len(), usize::max)
+        }
+        serde_json::Value::Object(entries) => {
+            entries.values().map(longest_json_array).max().unwrap_or(0)
+        }
+        _ => 0,
+    }
+}
+
+// the other way around, struct instances are written as objects of their fields. gives back
+// what couldn't be written when there's something JSON has no way to hold
+fn value_to_json(value: &Value) -> Result<serde_json::Value, String> {
+    Ok(match value {
+        Value::NullValue(_) => serde_json::Value::Null,
+        Value::BoolValue(value) => serde_json::Value::Bool(value.value),
+        // whole numbers are written without a '.0'
+        Value::NumberValue(number) if number.value.fract() == 0.0 && number.value.abs() < 1e15 => {
+            serde_json::Value::from(number.value as i64)
+        }
+        Value::NumberValue(number) => match serde_json::Number::from_f64(number.value) {
+            Some(number) => serde_json::Value::Number(number),
+            None => return Err(format!("the number {}", number.value)),
+        },
+        Value::StringValue(string) => serde_json::Value::String(string.value.clone()),
+        Value::ListValue(list) => serde_json::Value::Array(
+            list.elements.iter().map(value_to_json).collect::<Result<_, _>>()?,
+        ),
+        Value::MapValue(map) => serde_json::Value::Object(
+            map.entries
+                .iter()
+                .map(|(key, value)| Ok((key.clone(), value_to_json(value)?)))
+                .collect::<Result<_, String>>()?,
+        ),
+        Value::StructInstance(instance) => serde_json::Value::Object(
+            instance
+                .fields
+                .iter()
+                .map(|(key, value)| Ok((key.clone(), value_to_json(value)?)))
+                .collect::<Result<_, String>>()?,
+        ),
+        value => return Err(format!("a {}", value.object_type())),
+    })
+}
+
+// a stable merge sort that stops at the first error the comparison gives back. 'less' tells
+// whether its first value belongs before its second
+fn merge_sort(
+    values: Vec<Value>,
+    less: &mut dyn FnMut(&Value, &Value) -> Result<bool, StandardError>,
+) -> Result<Vec<Value>, StandardError> {
+    if values.len() < 2 {
+        return Ok(values);
+    }
+
+    let mut left = values;
+    let right = left.split_off(left.len() / 2);
+    let mut left = merge_sort(left, less)?.into_iter().peekable();
+    let mut right = merge_sort(right, less)?.into_iter().peekable();
+    let mut sorted = Vec::with_capacity(left.len() + right.len());
+
+    while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+        // equal elements keep their order, the left one goes first
+        if less(b, a)? {
+            sorted.push(right.next().unwrap());
+        } else {
+            sorted.push(left.next().unwrap());
+        }
+    }
+
+    sorted.extend(left);
+    sorted.extend(right);
+
+    Ok(sorted)
+}
+
 #[derive(Debug, Clone)]
 pub struct BuiltInFunction {
     pub name: String,
-    pub context: Option<Rc<RefCell<Context>>>,
+    pub context: Option<Weak<RefCell<Context>>>,
     pub pos_start: Option<Position>,
     pub pos_end: Option<Position>,
 }
@@ -36,21 +158,12 @@ impl BuiltInFunction {
     }
 
     pub fn generate_new_context(&self) -> Rc<RefCell<Context>> {
-        let mut new_context = Context::new(
-            self.name.clone(),
-            Some(self.context.as_ref().unwrap().clone()),
-            self.pos_start.clone(),
-        );
-        let parent_st = self
-            .context
+        let parent = self.context.as_ref().and_then(Weak::upgrade);
+        let parent_st = parent
             .as_ref()
-            .unwrap()
-            .borrow()
-            .symbol_table
-            .as_ref()
-            .unwrap()
-            .clone();
-        new_context.symbol_table = Some(Rc::new(RefCell::new(SymbolTable::new(Some(parent_st)))));
+            .map(|parent| parent.borrow().symbol_table.as_ref().unwrap().clone());
+        let mut new_context = Context::new(self.name.clone(), parent, self.pos_start.clone());
+        new_context.symbol_table = Some(Rc::new(RefCell::new(SymbolTable::new(parent_st))));
 
         Rc::new(RefCell::new(new_context))
     }
@@ -117,7 +230,38 @@ impl BuiltInFunction {
         result.success(None)
     }
 
-    pub fn execute(&self, args: &[Value]) -> RuntimeResult {
+    // like check_and_populate_args, but any number of extra arguments may follow the named ones
+    pub fn check_and_populate_variadic_args(
+        &self,
+        arg_names: &[String],
+        args: &[Value],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+
+        if args.len() < arg_names.len() {
+            return result.failure(Some(StandardError::new(
+                "invalid function call",
+                self.pos_start.as_ref().unwrap().clone(),
+                self.pos_end.as_ref().unwrap().clone(),
+                Some(
+                    format!(
+                        "{} takes at least {} positional argument(s) but the program gave {}",
+                        self.name,
+                        arg_names.len(),
+                        args.len()
+                    )
+                    .as_str(),
+                ),
+            )));
+        }
+
+        self.populate_args(arg_names, &args[..arg_names.len()], exec_ctx);
+
+        result.success(None)
+    }
+
+    pub fn execute(&self, args: &[Value], caller: &Interpreter) -> RuntimeResult {
         let exec_context = self.generate_new_context();
 
         match self.name.as_str() {
@@ -129,10 +273,36 @@ impl BuiltInFunction {
             "tonumber" => self.execute_tonumber(args, exec_context),
             "length" => self.execute_length(args, exec_context),
             "uhoh" => self.execute_error(args, exec_context),
+            "assert" => self.execute_assert(args, exec_context),
+            "exit" => self.execute_exit(args, exec_context),
             "type" => self.execute_type(args, exec_context),
-            "run" => self.execute_exec(args, exec_context),
+            "run" => self.execute_exec(args, exec_context, caller),
             "_env" => self.execute_env(args, exec_context),
             "inline"  => self.execute_inline(args, exec_context),
+            "servef" => self.execute_printf(args, exec_context),
+            "serve_table" => self.execute_print_table(args, exec_context),
+            "progress" => self.execute_progress(args, exec_context),
+            "apply" => self.execute_apply(args, exec_context, caller),
+            "map" | "filter" => self.execute_map(args, exec_context, caller),
+            "reduce" => self.execute_reduce(args, exec_context, caller),
+            "sort" => self.execute_sort(args, exec_context, caller),
+            "benchmark" => self.execute_benchmark(args, exec_context, caller),
+            "jsonparse" => self.execute_jsonparse(args, exec_context, caller),
+            "jsonstring" => self.execute_jsonstring(args, exec_context),
+            "split" => self.execute_split(args, exec_context, caller),
+            "join" => self.execute_join(args, exec_context),
+            "trim" | "trimstart" | "trimend" => self.execute_trim(args, exec_context),
+            "replace" => self.execute_replace(args, exec_context),
+            "contains" => self.execute_contains(args, exec_context),
+            "slice" => self.execute_slice(args, exec_context),
+            "sqrt" | "abs" | "floor" | "ceil" | "round" | "sin" | "cos" | "tan" | "log" | "log2"
+            | "exp" => self.execute_math(args, exec_context),
+            "random" => self.execute_random(args, exec_context),
+            "capitalize" | "title_case" => self.execute_capitalize(args, exec_context),
+            "reverse" => self.execute_reverse(args, exec_context),
+            "push" => self.execute_push(args, exec_context, caller),
+            "pop" => self.execute_pop(args, exec_context),
+            "get" => self.execute_get(args, exec_context),
             "rest"   => self.execute_rest(args, exec_context),
             _ => panic!("CRITICAL ERROR: BUILT IN NAME IS NOT DEFINED"),
         }
@@ -140,15 +310,171 @@ impl BuiltInFunction {
 
     pub fn execute_print(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
         let mut result = RuntimeResult::new();
-        result.register(self.check_and_populate_args(&["value".to_string()], args, exec_ctx));
+        result.register(self.check_and_populate_variadic_args(
+            &["value".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let output = args.iter().map(|arg| arg.as_string()).collect::<Vec<_>>().join(" ");
+
+        println!("{output}");
+
+        result.success(Some(Value::NullValue(Null::new())))
+    }
+
+    pub fn execute_printf(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_variadic_args(
+            &["template".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let template_arg = args[0].clone();
+
+        let template = match &template_arg {
+            Value::StringValue(string) => string.as_string(),
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type string",
+                    template_arg.position_start().unwrap().clone(),
+                    template_arg.position_end().unwrap().clone(),
+                    Some("add a template like '{} + {} = {}' to fill in"),
+                )));
+            }
+        };
+
+        let output = result.register(self.format_template(&template, &args[1..], &template_arg));
+
+        if result.should_return() {
+            return result;
+        }
+
+        println!("{}", output.unwrap().as_string());
+
+        result.success(Some(Value::NullValue(Null::new())))
+    }
+
+    pub fn execute_print_table(
+        &self,
+        args: &[Value],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["rows".to_string()], args, exec_ctx));
 
         if result.should_return() {
             return result;
         }
 
-        println!("{}", args[0].as_string());
+        let rows_arg = args[0].clone();
+        let mut rows: Vec<Vec<String>> = Vec::new();
+
+        let expected_rows = || {
+            StandardError::new(
+                "expected type list of lists",
+                rows_arg.position_start().unwrap().clone(),
+                rows_arg.position_end().unwrap().clone(),
+                Some("add the rows to print like [[\"name\", \"age\"], [\"maid\", 2]]"),
+            )
+        };
+
+        match &rows_arg {
+            Value::ListValue(list) => {
+                for row in &list.elements {
+                    match row {
+                        Value::ListValue(cells) => {
+                            rows.push(cells.elements.iter().map(|cell| cell.as_string()).collect())
+                        }
+                        _ => return result.failure(Some(expected_rows())),
+                    }
+                }
+            }
+            _ => return result.failure(Some(expected_rows())),
+        }
+
+        // every column is as wide as its widest cell
+        let mut widths: Vec<usize> = Vec::new();
+
+        for row in &rows {
+            for (i, cell) in row.iter().enumerate() {
+                let width = cell.chars().count();
+
+                match widths.get_mut(i) {
+                    Some(column_width) => *column_width = (*column_width).max(width),
+                    None => widths.push(width),
+                }
+            }
+        }
+
+        for row in &rows {
+            let line = row
+                .iter()
+                .enumerate()
+                .map(|(i, cell)| format!("{cell:<width$}", width = widths[i]))
+                .collect::<Vec<_>>()
+                .join("  ");
+
+            println!("{}", line.trim_end());
+        }
+
+        result.success(Some(Value::NullValue(Null::new())))
+    }
+
+    // fills every '{}' in the template with the next value, in order
+    pub fn format_template(
+        &self,
+        template: &str,
+        values: &[Value],
+        template_arg: &Value,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let placeholders = template.matches("{}").count();
+
+        if placeholders != values.len() {
+            return result.failure(Some(StandardError::new(
+                format!(
+                    "template has {placeholders} placeholder(s) but the program gave {} value(s)",
+                    values.len()
+                )
+                .as_str(),
+                self.pos_start.as_ref().unwrap().clone(),
+                self.pos_end.as_ref().unwrap().clone(),
+                Some("give one value for every '{}' in the template"),
+            )));
+        }
+
+        let mut output = String::new();
+        let mut values = values.iter();
+        let mut pieces = template.split("{}").peekable();
+
+        while let Some(piece) = pieces.next() {
+            if piece.contains('{') || piece.contains('}') {
+                return result.failure(Some(StandardError::new(
+                    "template has an unmatched bracket",
+                    template_arg.position_start().unwrap().clone(),
+                    template_arg.position_end().unwrap().clone(),
+                    Some("placeholders are written as '{}'"),
+                )));
+            }
+
+            output.push_str(piece);
 
-        result.success(Some(Number::null_value()))
+            if pieces.peek().is_some() {
+                output.push_str(&values.next().unwrap().as_string());
+            }
+        }
+
+        result.success(Some(Str::from(&output)))
     }
 
     pub fn execute_input(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
@@ -188,25 +514,89 @@ impl BuiltInFunction {
 
     pub fn execute_inline(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
         let mut result = RuntimeResult::new();
-        result.register(self.check_and_populate_args(&["text".to_string()], args, exec_ctx));
+        result.register(self.check_and_populate_variadic_args(
+            &["text".to_string()],
+            args,
+            exec_ctx,
+        ));
         if result.should_return() { return result; }
 
-        let text_arg = args[0].clone();
-        let s = match &text_arg {
-            Value::StringValue(string) => string.as_string(),
-            _ => {
-                return result.failure(Some(StandardError::new(
-                    "expected type string",
-                    text_arg.position_start().unwrap().clone(),
-                    text_arg.position_end().unwrap().clone(),
-                    Some("add the text to print without a newline"),
-                )));
+        let mut texts: Vec<String> = Vec::new();
+
+        for text_arg in args {
+            match text_arg {
+                Value::StringValue(string) => texts.push(string.as_string()),
+                _ => {
+                    return result.failure(Some(StandardError::new(
+                        "expected type string",
+                        text_arg.position_start().unwrap().clone(),
+                        text_arg.position_end().unwrap().clone(),
+                        Some("add the text to print without a newline"),
+                    )));
+                }
             }
-        };
+        }
+
+        print!("{}", texts.join(" "));
+        let _ = stdout().flush();
+        result.success(Some(Value::NullValue(Null::new())))
+    }
+
+    pub fn execute_progress(
+        &self,
+        args: &[Value],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["current".to_string(), "total".to_string()],
+            args,
+            exec_ctx,
+        ));
+        if result.should_return() { return result; }
+
+        let mut numbers: Vec<f64> = Vec::new();
+
+        for arg in args {
+            match arg {
+                Value::NumberValue(n) => numbers.push(n.value),
+                _ => {
+                    return result.failure(Some(StandardError::new(
+                        "expected type number",
+                        arg.position_start().unwrap().clone(),
+                        arg.position_end().unwrap().clone(),
+                        Some("pass how far along the work is and the total, e.g., progress(3, 10)"),
+                    )));
+                }
+            }
+        }
+
+        let (current, total) = (numbers[0], numbers[1]);
+
+        if total <= 0.0 {
+            return result.failure(Some(StandardError::new(
+                "total must be greater than 0",
+                args[1].position_start().unwrap().clone(),
+                args[1].position_end().unwrap().clone(),
+                None,
+            )));
+        }
+
+        let ratio = (current / total).clamp(0.0, 1.0);
+        let filled = (ratio * PROGRESS_BAR_WIDTH as f64).round() as usize;
+        let bar = format!(
+            "[{}{}] {:>3}%",
+            "#".repeat(filled),
+            "-".repeat(PROGRESS_BAR_WIDTH - filled),
+            (ratio * 100.0).round()
+        );
 
-        print!("{}", s);
+        // redraw over the same line, and move on once the work is done
+        print!("\r{bar}");
+        if ratio >= 1.0 { println!(); }
         let _ = stdout().flush();
-        result.success(Some(Number::null_value()))
+
+        result.success(Some(Str::from(&bar)))
     }
 
     pub fn execute_rest(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
@@ -229,7 +619,7 @@ impl BuiltInFunction {
 
         let dur = Duration::from_micros((secs * 1_000_000.0) as u64);
         thread::sleep(dur);
-        result.success(Some(Number::null_value()))
+        result.success(Some(Value::NullValue(Null::new())))
     }
 
     pub fn execute_read(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
@@ -331,7 +721,7 @@ impl BuiltInFunction {
             }
         }
 
-        result.success(Some(Number::null_value()))
+        result.success(Some(Value::NullValue(Null::new())))
     }
 
     pub fn execute_tostring(
@@ -364,7 +754,8 @@ impl BuiltInFunction {
         let string_to_convert = args[0].clone();
 
         let value: f64 = match &string_to_convert {
-            Value::StringValue(string) => match string.as_string().parse() {
+            // stray spaces around the number, like from 'process' input, are fine
+            Value::StringValue(string) => match string.as_string().trim().parse() {
                 Ok(number) => number,
                 Err(e) => {
                     return result.failure(Some(StandardError::new(
@@ -398,15 +789,17 @@ impl BuiltInFunction {
 
         let object_arg = args[0].clone();
 
+        // the size of a collection: characters of a string, elements of a list, entries of a map
         let length: f64 = match &object_arg {
-            Value::StringValue(value) => value.value.len() as f64,
+            Value::StringValue(value) => value.value.chars().count() as f64,
             Value::ListValue(value) => value.elements.len() as f64,
+            Value::MapValue(value) => value.entries.len() as f64,
             _ => {
                 return result.failure(Some(StandardError::new(
-                    "expected type string or list",
+                    "expected type string, list or map",
                     object_arg.position_start().unwrap().clone(),
                     object_arg.position_end().unwrap().clone(),
-                    None,
+                    Some("only strings, lists and maps have a length"),
                 )));
             }
         };
@@ -414,88 +807,767 @@ impl BuiltInFunction {
         result.success(Some(Number::from(length)))
     }
 
-    pub fn execute_error(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+    pub fn execute_split(
+        &self,
+        args: &[Value],
+        exec_ctx: Rc<RefCell<Context>>,
+        caller: &Interpreter,
+    ) -> RuntimeResult {
         let mut result = RuntimeResult::new();
-        result.register(self.check_and_populate_args(&["msg".to_string()], args, exec_ctx));
+        result.register(self.check_and_populate_args(
+            &["str".to_string(), "delimiter".to_string()],
+            args,
+            exec_ctx,
+        ));
 
         if result.should_return() {
             return result;
         }
 
-        let error = args[0].clone();
+        let (str_arg, delimiter_arg) = (args[0].clone(), args[1].clone());
 
-        let message = match &error {
-            Value::StringValue(_) => error,
+        let string = match &str_arg {
+            Value::StringValue(string) => string.as_string(),
             _ => {
                 return result.failure(Some(StandardError::new(
                     "expected type string",
-                    error.position_start().unwrap().clone(),
-                    error.position_end().unwrap().clone(),
-                    Some("add an error message"),
+                    str_arg.position_start().unwrap().clone(),
+                    str_arg.position_end().unwrap().clone(),
+                    Some("add the string you would like to split"),
                 )));
             }
         };
 
-        result.failure(Some(StandardError::new(
-            message.as_string().as_str(),
-            message.position_start().unwrap().clone(),
-            message.position_end().unwrap().clone(),
-            None,
-        )))
-    }
+        let delimiter = match &delimiter_arg {
+            Value::StringValue(delimiter) => delimiter.as_string(),
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type string",
+                    delimiter_arg.position_start().unwrap().clone(),
+                    delimiter_arg.position_end().unwrap().clone(),
+                    Some("add a delimiter like ',', or '' to split every character"),
+                )));
+            }
+        };
 
-    pub fn execute_type(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
-        let mut result = RuntimeResult::new();
-        result.register(self.check_and_populate_args(&["value".to_string()], args, exec_ctx));
+        // an empty delimiter splits between every character
+        let pieces: Vec<Value> = if delimiter.is_empty() {
+            string.chars().map(|c| Str::from(&c.to_string())).collect()
+        } else {
+            string.split(delimiter.as_str()).map(Str::from).collect()
+        };
 
-        if result.should_return() {
-            return result;
+        if let Err(error) = caller.limits.check_list_length(
+            pieces.len(),
+            str_arg.position_start().unwrap(),
+            delimiter_arg.position_end().unwrap(),
+        ) {
+            return result.failure(Some(error));
         }
 
-        result.success(Some(Str::from(
-            args[0].object_type().to_string().as_str(),
-        )))
+        result.success(Some(Value::ListValue(List::new(pieces))))
     }
 
-    pub fn execute_exec(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+    pub fn execute_join(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
         let mut result = RuntimeResult::new();
-        result.register(self.check_and_populate_args(&["code".to_string()], args, exec_ctx));
+        result.register(self.check_and_populate_args(
+            &["list".to_string(), "separator".to_string()],
+            args,
+            exec_ctx,
+        ));
 
         if result.should_return() {
             return result;
         }
 
-        let code_arg = args[0].clone();
+        let (list_arg, separator_arg) = (args[0].clone(), args[1].clone());
 
-        let code = match &code_arg {
-            Value::StringValue(maid) => maid.as_string(),
+        let elements = match &list_arg {
+            Value::ListValue(list) => list.elements.clone(),
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type list",
+                    list_arg.position_start().unwrap().clone(),
+                    list_arg.position_end().unwrap().clone(),
+                    Some("add the list you would like to join, like [1, 2, 3]"),
+                )));
+            }
+        };
+
+        let separator = match &separator_arg {
+            Value::StringValue(separator) => separator.as_string(),
             _ => {
                 return result.failure(Some(StandardError::new(
                     "expected type string",
-                    code_arg.position_start().unwrap().clone(),
-                    code_arg.position_end().unwrap().clone(),
-                    Some("add the maid code you would like to execute"),
+                    separator_arg.position_start().unwrap().clone(),
+                    separator_arg.position_end().unwrap().clone(),
+                    Some("add a separator like ', ', or '' to join without one"),
                 )));
             }
         };
 
-        let mut lexer = Lexer::new(&code_arg.position_start().unwrap().filename, code.clone());
-        let token_result = lexer.make_tokens();
+        let joined = elements.iter().map(|element| element.as_string()).collect::<Vec<_>>();
 
-        if token_result.is_err() {
-            return result.failure(token_result.err());
-        }
+        result.success(Some(Str::from(&joined.join(&separator))))
+    }
 
-        let mut parser = Parser::new(&token_result.ok().unwrap());
-        let ast = parser.parse();
+    pub fn execute_replace(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["text".to_string(), "from".to_string(), "to".to_string()],
+            args,
+            exec_ctx,
+        ));
 
-        if ast.error.is_some() {
-            return result.failure(ast.error);
+        if result.should_return() {
+            return result;
         }
 
-        let mut interpreter = Interpreter::new();
-        let external_context =
-            Rc::new(RefCell::new(Context::new("<exec>".to_string(), None, None)));
+        let mut strings: Vec<String> = Vec::new();
+
+        for (arg, help) in args.iter().zip([
+            "add the string to replace in",
+            "add the substring to look for",
+            "add the string to put in its place",
+        ]) {
+            match arg {
+                Value::StringValue(string) => strings.push(string.as_string()),
+                _ => {
+                    return result.failure(Some(StandardError::new(
+                        "expected type string",
+                        arg.position_start().unwrap().clone(),
+                        arg.position_end().unwrap().clone(),
+                        Some(help),
+                    )));
+                }
+            }
+        }
+
+        if strings[1].is_empty() {
+            return result.failure(Some(StandardError::new(
+                "cannot replace an empty string",
+                args[1].position_start().unwrap().clone(),
+                args[1].position_end().unwrap().clone(),
+                Some("pass the substring that should be replaced"),
+            )));
+        }
+
+        result.success(Some(Str::from(&strings[0].replace(&strings[1], &strings[2]))))
+    }
+
+    pub fn execute_contains(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["haystack".to_string(), "needle".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let found = match args[0].contains(&args[1]) {
+            Ok(found) => found,
+            Err(error) => return result.failure(Some(error)),
+        };
+
+        result.success(Some(Bool::from(found)))
+    }
+
+    pub fn execute_slice(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["value".to_string(), "start".to_string(), "end".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let mut bounds: Vec<f64> = Vec::new();
+
+        for bound_arg in &args[1..] {
+            match bound_arg {
+                Value::NumberValue(number)
+                    if number.value.is_finite() && number.value.fract() == 0.0 =>
+                {
+                    bounds.push(number.value)
+                }
+                Value::NumberValue(number) => {
+                    return result.failure(Some(StandardError::new(
+                        "expected a whole number",
+                        number.pos_start.clone().unwrap(),
+                        number.pos_end.clone().unwrap(),
+                        Some("indices can't be fractional, NaN or infinite"),
+                    )));
+                }
+                _ => {
+                    return result.failure(Some(StandardError::new(
+                        "expected type number",
+                        bound_arg.position_start().unwrap().clone(),
+                        bound_arg.position_end().unwrap().clone(),
+                        Some("slice between two indices like 'slice(value, 0, 2)'"),
+                    )));
+                }
+            }
+        }
+
+        // negative indices count from the end, out of range ones are clamped
+        let range = |length: usize| {
+            let [start, end] = [bounds[0], bounds[1]].map(|bound| {
+                let index = bound as isize;
+
+                if index < 0 {
+                    (length as isize + index).max(0) as usize
+                } else {
+                    (index as usize).min(length)
+                }
+            });
+
+            start..end.max(start)
+        };
+
+        let value_arg = args[0].clone();
+
+        match &value_arg {
+            Value::StringValue(string) => {
+                let chars: Vec<char> = string.value.chars().collect();
+                let sliced: String = chars[range(chars.len())].iter().collect();
+
+                result.success(Some(Str::from(&sliced)))
+            }
+            Value::ListValue(list) => {
+                let sliced = list.elements[range(list.elements.len())].to_vec();
+
+                result.success(Some(Value::ListValue(List::new(sliced))))
+            }
+            _ => result.failure(Some(StandardError::new(
+                "expected type string or list",
+                value_arg.position_start().unwrap().clone(),
+                value_arg.position_end().unwrap().clone(),
+                Some("add the string or list you would like to slice"),
+            ))),
+        }
+    }
+
+    // shared by 'trim', 'trimstart' and 'trimend', which only differ in the side they strip
+    pub fn execute_trim(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["str".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let str_arg = args[0].clone();
+
+        let string = match &str_arg {
+            Value::StringValue(string) => string.as_string(),
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type string",
+                    str_arg.position_start().unwrap().clone(),
+                    str_arg.position_end().unwrap().clone(),
+                    Some(format!("pass a string to {}", self.name).as_str()),
+                )));
+            }
+        };
+
+        let trimmed = match self.name.as_str() {
+            "trimstart" => string.trim_start(),
+            "trimend" => string.trim_end(),
+            _ => string.trim(),
+        };
+
+        result.success(Some(Str::from(trimmed)))
+    }
+
+    pub fn execute_capitalize(
+        &self,
+        args: &[Value],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["text".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let text_arg = args[0].clone();
+
+        let text = match &text_arg {
+            Value::StringValue(string) => string.as_string(),
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type string",
+                    text_arg.position_start().unwrap().clone(),
+                    text_arg.position_end().unwrap().clone(),
+                    Some(format!("pass a string to {}", self.name).as_str()),
+                )));
+            }
+        };
+
+        // 'capitalize' only touches the very first character, 'title_case' the first of
+        // every word. uppercasing can give more than one character, like 'ß' to 'SS'
+        let mut capitalized = String::with_capacity(text.len());
+        let mut at_word_start = true;
+
+        for character in text.chars() {
+            if at_word_start && !character.is_whitespace() {
+                capitalized.extend(character.to_uppercase());
+                at_word_start = false;
+            } else {
+                capitalized.push(character);
+            }
+
+            if self.name == "title_case" && character.is_whitespace() {
+                at_word_start = true;
+            }
+        }
+
+        result.success(Some(Str::from(&capitalized)))
+    }
+
+    pub fn execute_push(
+        &self,
+        args: &[Value],
+        exec_ctx: Rc<RefCell<Context>>,
+        caller: &Interpreter,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["list".to_string(), "value".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        match &args[0] {
+            Value::ListValue(list) => match caller.limits.check_list_length(
+                list.elements.len() + 1,
+                args[0].position_start().unwrap(),
+                args[1].position_end().unwrap(),
+            ) {
+                Ok(()) => result.success(Some(list.clone().push(args[1].clone()))),
+                Err(error) => result.failure(Some(error)),
+            },
+            value => result.failure(Some(StandardError::new(
+                "expected type list",
+                value.position_start().unwrap().clone(),
+                value.position_end().unwrap().clone(),
+                Some("pass the list you would like to add the value to"),
+            ))),
+        }
+    }
+
+    // gives back the element taken off, not the shortened list
+    pub fn execute_pop(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["list".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        match &args[0] {
+            Value::ListValue(list) => match list.elements.last() {
+                Some(last) => result.success(Some(last.clone())),
+                None => result.failure(Some(StandardError::new(
+                    "cannot pop from empty list",
+                    args[0].position_start().unwrap().clone(),
+                    args[0].position_end().unwrap().clone(),
+                    Some("check the list has elements with 'length' before popping"),
+                ))),
+            },
+            value => result.failure(Some(StandardError::new(
+                "expected type list",
+                value.position_start().unwrap().clone(),
+                value.position_end().unwrap().clone(),
+                Some("pass the list you would like to take the last element from"),
+            ))),
+        }
+    }
+
+    // like '^', but anything that isn't there comes back as null instead of an error
+    pub fn execute_get(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["value".to_string(), "index".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let (value_arg, index_arg) = (&args[0], &args[1]);
+
+        let found = match (value_arg, index_arg) {
+            (Value::ListValue(list), Value::NumberValue(index)) => {
+                match index.index_into(list.elements.len()) {
+                    Ok(index) => index.map(|index| list.retrieve(index)),
+                    Err(error) => return result.failure(Some(error)),
+                }
+            }
+            (Value::StringValue(string), Value::NumberValue(index)) => {
+                let characters: Vec<char> = string.value.chars().collect();
+
+                match index.index_into(characters.len()) {
+                    Ok(index) => index.map(|index| Str::from(&characters[index].to_string())),
+                    Err(error) => return result.failure(Some(error)),
+                }
+            }
+            (Value::MapValue(map), Value::StringValue(key)) => map.entries.get(&key.value).cloned(),
+            (Value::ListValue(_) | Value::StringValue(_), _) => {
+                return result.failure(Some(StandardError::new(
+                    "expected type number",
+                    index_arg.position_start().unwrap().clone(),
+                    index_arg.position_end().unwrap().clone(),
+                    Some("lists and strings are indexed by position"),
+                )));
+            }
+            (Value::MapValue(_), _) => {
+                return result.failure(Some(StandardError::new(
+                    "expected type string",
+                    index_arg.position_start().unwrap().clone(),
+                    index_arg.position_end().unwrap().clone(),
+                    Some("map keys are always strings"),
+                )));
+            }
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type list, string or map",
+                    value_arg.position_start().unwrap().clone(),
+                    value_arg.position_end().unwrap().clone(),
+                    Some("pass the list, string or map you would like to look in"),
+                )));
+            }
+        };
+
+        result.success(Some(found.unwrap_or(Value::NullValue(Null::new()))))
+    }
+
+    pub fn execute_reverse(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["value".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        match &args[0] {
+            Value::ListValue(list) => result.success(Some(list.clone().reverse())),
+            Value::StringValue(string) => {
+                result.success(Some(Str::from(&string.value.chars().rev().collect::<String>())))
+            }
+            value => result.failure(Some(StandardError::new(
+                "expected type string or list",
+                value.position_start().unwrap().clone(),
+                value.position_end().unwrap().clone(),
+                Some("pass the string or list you would like to reverse"),
+            ))),
+        }
+    }
+
+    // angles for 'sin', 'cos' and 'tan' are in radians, 'log' is the natural logarithm.
+    // 'round' takes an optional number of decimal places to keep
+    pub fn execute_math(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let max_args = if self.name == "round" { 2 } else { 1 };
+        result.register(self.check_and_populate_variadic_args(&["x".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        if args.len() > max_args {
+            return result.failure(Some(StandardError::new(
+                "invalid function call",
+                self.pos_start.as_ref().unwrap().clone(),
+                self.pos_end.as_ref().unwrap().clone(),
+                Some(
+                    format!(
+                        "{} takes {} positional argument(s) but the program gave {}",
+                        self.name,
+                        if max_args == 1 { "1" } else { "1 or 2" },
+                        args.len()
+                    )
+                    .as_str(),
+                ),
+            )));
+        }
+
+        let number_arg = args[0].clone();
+
+        let number = match &number_arg {
+            Value::NumberValue(number) => number.value,
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type number",
+                    number_arg.position_start().unwrap().clone(),
+                    number_arg.position_end().unwrap().clone(),
+                    Some(format!("pass a number to {}", self.name).as_str()),
+                )));
+            }
+        };
+
+        let value = match self.name.as_str() {
+            "sqrt" if number < 0.0 => {
+                return result.failure(Some(StandardError::new(
+                    "cannot take the square root of a negative number",
+                    number_arg.position_start().unwrap().clone(),
+                    number_arg.position_end().unwrap().clone(),
+                    Some("use abs() first if only the size of the number matters"),
+                )));
+            }
+            "log" | "log2" if number <= 0.0 => {
+                return result.failure(Some(StandardError::new(
+                    "cannot take the logarithm of a number that isn't above 0",
+                    number_arg.position_start().unwrap().clone(),
+                    number_arg.position_end().unwrap().clone(),
+                    Some(format!("{} only takes numbers above 0", self.name).as_str()),
+                )));
+            }
+            "sqrt" => number.sqrt(),
+            "abs" => number.abs(),
+            "floor" => number.floor(),
+            "ceil" => number.ceil(),
+            "sin" => number.sin(),
+            "cos" => number.cos(),
+            "tan" => number.tan(),
+            "log" => number.ln(),
+            "log2" => number.log2(),
+            "exp" => number.exp(),
+            // halves round away from zero
+            _ => match args.get(1) {
+                None => number.round(),
+                Some(Value::NumberValue(places)) if places.value.fract() == 0.0 => {
+                    // kept to the powers of 10 a number can hold, past them the scale would
+                    // turn into infinity or 0
+                    let scale = 10f64.powf(places.value.clamp(-308.0, 308.0));
+                    let scaled = number * scale;
+
+                    // a number too large to scale has no decimals left to round off
+                    if scaled.is_finite() {
+                        scaled.round() / scale
+                    } else {
+                        number
+                    }
+                }
+                Some(places) => {
+                    return result.failure(Some(StandardError::new(
+                        "expected a whole number",
+                        places.position_start().unwrap().clone(),
+                        places.position_end().unwrap().clone(),
+                        Some("add how many decimal places to keep, like round(x, 2)"),
+                    )));
+                }
+            },
+        };
+
+        result.success(Some(Number::from(value)))
+    }
+
+    pub fn execute_random(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["min".to_string(), "max".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let (min, max) = match (&args[0], &args[1]) {
+            (Value::NumberValue(min), Value::NumberValue(max)) => (min.value, max.value),
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type number",
+                    args[0].position_start().unwrap().clone(),
+                    args[1].position_end().unwrap().clone(),
+                    Some("pass the range like 'random(1, 7)'"),
+                )));
+            }
+        };
+
+        if min >= max {
+            return result.failure(Some(StandardError::new(
+                "min must be smaller than max",
+                args[0].position_start().unwrap().clone(),
+                args[1].position_end().unwrap().clone(),
+                Some("numbers fall in [min, max) and can't be seeded, so they differ every run"),
+            )));
+        }
+
+        result.success(Some(Number::from(min + next_random() * (max - min))))
+    }
+
+    pub fn execute_error(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["msg".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let error = args[0].clone();
+
+        let message = match &error {
+            Value::StringValue(_) => error,
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type string",
+                    error.position_start().unwrap().clone(),
+                    error.position_end().unwrap().clone(),
+                    Some("add an error message"),
+                )));
+            }
+        };
+
+        result.failure(Some(StandardError::new(
+            message.as_string().as_str(),
+            message.position_start().unwrap().clone(),
+            message.position_end().unwrap().clone(),
+            None,
+        )))
+    }
+
+    // tosses an error with the message, pointing at the condition, unless the condition is true
+    pub fn execute_assert(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["condition".to_string(), "message".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let (condition, message_arg) = (&args[0], &args[1]);
+
+        let Value::StringValue(message) = message_arg else {
+            return result.failure(Some(StandardError::new(
+                "expected type string",
+                message_arg.position_start().unwrap().clone(),
+                message_arg.position_end().unwrap().clone(),
+                Some("add the message to show when the condition isn't true"),
+            )));
+        };
+
+        if condition.is_true() {
+            return result.success(Some(Value::NullValue(Null::new())));
+        }
+
+        result.failure(Some(StandardError::new(
+            message.as_string().as_str(),
+            condition.position_start().unwrap().clone(),
+            condition.position_end().unwrap().clone(),
+            None,
+        )))
+    }
+
+    // stops the whole process straight away with the given status code. it never comes back as
+    // an error, so an 'unsafe' block can't catch it and no code after it runs
+    pub fn execute_exit(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["code".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let code_arg = &args[0];
+
+        let code = match code_arg {
+            // fractions are dropped and the code is kept within what an i32 can hold
+            Value::NumberValue(number) => {
+                number.value.trunc().clamp(i32::MIN as f64, i32::MAX as f64) as i32
+            }
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type number",
+                    code_arg.position_start().unwrap().clone(),
+                    code_arg.position_end().unwrap().clone(),
+                    Some("add the exit code, 0 means the script succeeded"),
+                )));
+            }
+        };
+
+        // anything written with 'inline' may still be waiting in the buffer
+        let _ = stdout().flush();
+
+        process::exit(code)
+    }
+
+    pub fn execute_type(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["value".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        result.success(Some(Str::from(
+            args[0].object_type().to_string().as_str(),
+        )))
+    }
+
+    pub fn execute_exec(
+        &self,
+        args: &[Value],
+        exec_ctx: Rc<RefCell<Context>>,
+        caller: &Interpreter,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["code".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let code_arg = args[0].clone();
+
+        let code = match &code_arg {
+            Value::StringValue(maid) => maid.as_string(),
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type string",
+                    code_arg.position_start().unwrap().clone(),
+                    code_arg.position_end().unwrap().clone(),
+                    Some("add the maid code you would like to execute"),
+                )));
+            }
+        };
+
+        let mut lexer = Lexer::new(&code_arg.position_start().unwrap().filename, code.clone());
+        let token_result = lexer.make_tokens();
+
+        if token_result.is_err() {
+            return result.failure(token_result.err());
+        }
+
+        let mut parser = Parser::new(&token_result.ok().unwrap());
+        let ast = parser.parse();
+
+        if ast.has_failed() {
+            return result.failure(ast.errors.into_iter().next());
+        }
+
+        let mut interpreter = Interpreter::new().called_from(caller);
+        let external_context =
+            Rc::new(RefCell::new(Context::new("<exec>".to_string(), None, None)));
         external_context.borrow_mut().symbol_table = Some(interpreter.global_symbol_table.clone());
         let external_result = interpreter.visit(ast.node.unwrap(), external_context.clone());
 
@@ -503,7 +1575,453 @@ impl BuiltInFunction {
             return result.failure(external_result.error);
         }
 
-        result.success(Some(Number::null_value()))
+        result.success(Some(Value::NullValue(Null::new())))
+    }
+
+    pub fn execute_apply(
+        &self,
+        args: &[Value],
+        exec_ctx: Rc<RefCell<Context>>,
+        caller: &Interpreter,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["func".to_string(), "args".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let (func_arg, args_arg) = (args[0].clone(), args[1].clone());
+
+        let func_args = match &args_arg {
+            Value::ListValue(list) => list.elements.clone(),
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type list",
+                    args_arg.position_start().unwrap().clone(),
+                    args_arg.position_end().unwrap().clone(),
+                    Some("add the arguments to call the function with, like [1, 2]"),
+                )));
+            }
+        };
+
+        let return_value = result.register(match func_arg {
+            Value::FunctionValue(ref func) => func.execute(&func_args, caller),
+            Value::BuiltInFunction(ref func) => func.execute(&func_args, caller),
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type function",
+                    func_arg.position_start().unwrap().clone(),
+                    func_arg.position_end().unwrap().clone(),
+                    Some("add the function you would like to call"),
+                )));
+            }
+        });
+
+        if result.should_return() {
+            return result;
+        }
+
+        result.success(return_value)
+    }
+
+    // 'map' gives back what the function returns for each element, 'filter' keeps the elements
+    // the function returns something true for
+    pub fn execute_map(
+        &self,
+        args: &[Value],
+        exec_ctx: Rc<RefCell<Context>>,
+        caller: &Interpreter,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["list".to_string(), "func".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let (list_arg, func_arg) = (&args[0], &args[1]);
+
+        let elements = match list_arg {
+            Value::ListValue(list) => &list.elements,
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type list",
+                    list_arg.position_start().unwrap().clone(),
+                    list_arg.position_end().unwrap().clone(),
+                    Some(format!("pass the list you would like to {}", self.name).as_str()),
+                )));
+            }
+        };
+
+        if !matches!(func_arg, Value::FunctionValue(_) | Value::BuiltInFunction(_)) {
+            return result.failure(Some(StandardError::new(
+                "expected type function",
+                func_arg.position_start().unwrap().clone(),
+                func_arg.position_end().unwrap().clone(),
+                Some("add the function to call with each element of the list"),
+            )));
+        }
+
+        // 'map' gives back as many elements as it's given, 'filter' never more. the list may
+        // come from before the limit was set, like one made by the default library
+        if let Err(error) = caller.limits.check_list_length(
+            elements.len(),
+            list_arg.position_start().unwrap(),
+            func_arg.position_end().unwrap(),
+        ) {
+            return result.failure(Some(error));
+        }
+
+        let mut values: Vec<Value> = Vec::new();
+
+        for element in elements.iter() {
+            let call_args = [element.clone()];
+            let returned = result.register(match func_arg {
+                Value::FunctionValue(func) => func.execute(&call_args, caller),
+                Value::BuiltInFunction(func) => func.execute(&call_args, caller),
+                _ => RuntimeResult::new(),
+            });
+
+            if result.should_return() {
+                return result;
+            }
+
+            let returned = returned.unwrap_or(Value::NullValue(Null::new()));
+
+            if self.name == "map" {
+                values.push(returned);
+            } else if returned.is_true() {
+                values.push(element.clone());
+            }
+        }
+
+        result.success(Some(Value::ListValue(List::new(values))))
+    }
+
+    // calls the function with the running total and each element in turn, the total starts
+    // out as 'initial' and is replaced by whatever the function gives back
+    pub fn execute_reduce(
+        &self,
+        args: &[Value],
+        exec_ctx: Rc<RefCell<Context>>,
+        caller: &Interpreter,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["list".to_string(), "func".to_string(), "initial".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let (list_arg, func_arg) = (&args[0], &args[1]);
+
+        let elements = match list_arg {
+            Value::ListValue(list) => &list.elements,
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type list",
+                    list_arg.position_start().unwrap().clone(),
+                    list_arg.position_end().unwrap().clone(),
+                    Some("pass the list you would like to reduce"),
+                )));
+            }
+        };
+
+        if !matches!(func_arg, Value::FunctionValue(_) | Value::BuiltInFunction(_)) {
+            return result.failure(Some(StandardError::new(
+                "expected type function",
+                func_arg.position_start().unwrap().clone(),
+                func_arg.position_end().unwrap().clone(),
+                Some("add a function taking the total so far and an element, like add(a, b)"),
+            )));
+        }
+
+        let mut total = args[2].clone();
+
+        for element in elements.iter() {
+            let call_args = [total.clone(), element.clone()];
+            let mut call_result = match func_arg {
+                Value::FunctionValue(func) => func.execute(&call_args, caller),
+                Value::BuiltInFunction(func) => func.execute(&call_args, caller),
+                _ => RuntimeResult::new(),
+            };
+
+            // point at the element the function failed on rather than inside the function
+            if let (Some(error), Some(pos_start), Some(pos_end)) = (
+                call_result.error.as_mut(),
+                element.position_start(),
+                element.position_end(),
+            ) {
+                *error.pos_start = pos_start;
+                *error.pos_end = pos_end;
+                error.traceback.call_stack.clear();
+            }
+
+            let returned = result.register(call_result);
+
+            if result.should_return() {
+                return result;
+            }
+
+            total = returned.unwrap_or(Value::NullValue(Null::new()));
+        }
+
+        result.success(Some(total))
+    }
+
+    // sorts a list of only numbers or only strings from smallest to largest, or any list with a
+    // function that compares two elements, giving back a number below 0 when the first goes
+    // before the second, above 0 when it goes after and 0 when either order will do
+    pub fn execute_sort(
+        &self,
+        args: &[Value],
+        exec_ctx: Rc<RefCell<Context>>,
+        caller: &Interpreter,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_variadic_args(
+            &["list".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        if args.len() > 2 {
+            return result.failure(Some(StandardError::new(
+                "invalid function call",
+                self.pos_start.as_ref().unwrap().clone(),
+                self.pos_end.as_ref().unwrap().clone(),
+                Some(
+                    format!(
+                        "sort takes 1 or 2 positional argument(s) but the program gave {}",
+                        args.len()
+                    )
+                    .as_str(),
+                ),
+            )));
+        }
+
+        let list_arg = &args[0];
+
+        let elements = match list_arg {
+            Value::ListValue(list) => list.elements.clone(),
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected type list",
+                    list_arg.position_start().unwrap().clone(),
+                    list_arg.position_end().unwrap().clone(),
+                    Some("pass the list you would like to sort"),
+                )));
+            }
+        };
+
+        let sorted = match args.get(1) {
+            Some(func_arg @ (Value::FunctionValue(_) | Value::BuiltInFunction(_))) => {
+                merge_sort(elements, &mut |a, b| {
+                    let call_args = [a.clone(), b.clone()];
+                    let call_result = match func_arg {
+                        Value::FunctionValue(func) => func.execute(&call_args, caller),
+                        Value::BuiltInFunction(func) => func.execute(&call_args, caller),
+                        _ => RuntimeResult::new(),
+                    };
+
+                    if let Some(error) = call_result.error {
+                        return Err(error);
+                    }
+
+                    match call_result.value {
+                        Some(Value::NumberValue(number)) => Ok(number.value < 0.0),
+                        _ => Err(StandardError::new(
+                            "expected type number",
+                            func_arg.position_start().unwrap().clone(),
+                            func_arg.position_end().unwrap().clone(),
+                            Some("the compare function should give back a number below 0, 0 or above 0"),
+                        )),
+                    }
+                })
+            }
+            Some(func_arg) => {
+                return result.failure(Some(StandardError::new(
+                    "expected type function",
+                    func_arg.position_start().unwrap().clone(),
+                    func_arg.position_end().unwrap().clone(),
+                    Some("add a function comparing two elements, or leave it out to sort from smallest to largest"),
+                )));
+            }
+            None => {
+                let comparable = elements.iter().all(|e| matches!(e, Value::NumberValue(_)))
+                    || elements.iter().all(|e| matches!(e, Value::StringValue(_)));
+
+                if !comparable {
+                    return result.failure(Some(StandardError::new(
+                        "sort requires comparable elements",
+                        list_arg.position_start().unwrap().clone(),
+                        list_arg.position_end().unwrap().clone(),
+                        Some("the list should hold only numbers or only strings, or pass a function to compare the elements"),
+                    )));
+                }
+
+                // strings don't take '<', so they're ordered by their characters instead
+                merge_sort(elements, &mut |a, b| match (a, b) {
+                    (Value::StringValue(a), Value::StringValue(b)) => Ok(a.value < b.value),
+                    _ => Ok(a.clone().perform_operation("<", b.clone())?.is_true()),
+                })
+            }
+        };
+
+        match sorted {
+            Ok(sorted) => result.success(Some(Value::ListValue(List::new(sorted)))),
+            Err(error) => result.failure(Some(error)),
+        }
+    }
+
+    // calls the function with no arguments the given number of times, giving back a map of the
+    // 'total' milliseconds all the calls took and the 'average' for one of them
+    pub fn execute_benchmark(
+        &self,
+        args: &[Value],
+        exec_ctx: Rc<RefCell<Context>>,
+        caller: &Interpreter,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["func".to_string(), "iterations".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let (func_arg, iterations_arg) = (&args[0], &args[1]);
+
+        if !matches!(func_arg, Value::FunctionValue(_) | Value::BuiltInFunction(_)) {
+            return result.failure(Some(StandardError::new(
+                "expected type function",
+                func_arg.position_start().unwrap().clone(),
+                func_arg.position_end().unwrap().clone(),
+                Some("add the function to time, it's called without any arguments"),
+            )));
+        }
+
+        let iterations = match iterations_arg {
+            Value::NumberValue(number) if number.value >= 1.0 && number.value.fract() == 0.0 => {
+                number.value as usize
+            }
+            _ => {
+                return result.failure(Some(StandardError::new(
+                    "expected a whole number above 0",
+                    iterations_arg.position_start().unwrap().clone(),
+                    iterations_arg.position_end().unwrap().clone(),
+                    Some("add how many times the function should be called"),
+                )));
+            }
+        };
+
+        let start = Instant::now();
+
+        for _ in 0..iterations {
+            result.register(match func_arg {
+                Value::FunctionValue(func) => func.execute(&[], caller),
+                Value::BuiltInFunction(func) => func.execute(&[], caller),
+                _ => RuntimeResult::new(),
+            });
+
+            if result.should_return() {
+                return result;
+            }
+        }
+
+        let total = start.elapsed().as_secs_f64() * 1000.0;
+        let timing = HashMap::from([
+            ("total".to_string(), Number::from(total)),
+            ("average".to_string(), Number::from(total / iterations as f64)),
+        ]);
+
+        result.success(Some(Map::from(timing)))
+    }
+
+    pub fn execute_jsonparse(
+        &self,
+        args: &[Value],
+        exec_ctx: Rc<RefCell<Context>>,
+        caller: &Interpreter,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["text".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let text_arg = &args[0];
+
+        let Value::StringValue(text) = text_arg else {
+            return result.failure(Some(StandardError::new(
+                "expected type string",
+                text_arg.position_start().unwrap().clone(),
+                text_arg.position_end().unwrap().clone(),
+                Some("add the JSON text you would like to read"),
+            )));
+        };
+
+        match serde_json::from_str(&text.value) {
+            Ok(json) => {
+                if let Err(error) = caller.limits.check_list_length(
+                    longest_json_array(&json),
+                    text_arg.position_start().unwrap(),
+                    text_arg.position_end().unwrap(),
+                ) {
+                    return result.failure(Some(error));
+                }
+
+                result.success(Some(json_to_value(json)))
+            }
+            Err(e) => result.failure(Some(StandardError::new(
+                format!("invalid JSON: {e}").as_str(),
+                text_arg.position_start().unwrap().clone(),
+                text_arg.position_end().unwrap().clone(),
+                None,
+            ))),
+        }
+    }
+
+    pub fn execute_jsonstring(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["value".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let value_arg = &args[0];
+
+        match value_to_json(value_arg) {
+            Ok(json) => result.success(Some(Str::from(&json.to_string()))),
+            Err(unwritable) => result.failure(Some(StandardError::new(
+                format!("JSON has no way to write {unwritable}").as_str(),
+                value_arg.position_start().unwrap().clone(),
+                value_arg.position_end().unwrap().clone(),
+                Some("only null, bools, numbers, strings, lists, maps and structs can be written"),
+            ))),
+        }
     }
 
     pub fn execute_env(&self, args: &[Value], exec_ctx: Rc<RefCell<Context>>) -> RuntimeResult {
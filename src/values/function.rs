@@ -1,4 +1,12 @@
-use std::{cell::RefCell, rc::Rc, sync::Arc};
+use std::{
+    cell::RefCell,
+    ptr,
+    rc::{Rc, Weak},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
 
 use crate::{
     errors::standard_error::StandardError,
@@ -8,16 +16,54 @@ use crate::{
     },
     lexing::position::Position,
     nodes::ast_node::AstNode,
-    values::{number::Number, value::Value},
+    syntax::attributes::MAX_CALL_DEPTH,
+    values::{list::List, null::Null, value::Value},
 };
 
+// hands out a unique id to every function definition, copies of a function share it
+static NEXT_FUNCTION_ID: AtomicUsize = AtomicUsize::new(0);
+
+// how a function reaches a table it was defined with. the copy of a function stored in that
+// table itself only links back weakly, or the two would keep each other alive for good
+#[derive(Debug, Clone)]
+pub enum TableLink {
+    Strong(Rc<RefCell<SymbolTable>>),
+    Weak(Weak<RefCell<SymbolTable>>),
+}
+
+impl TableLink {
+    pub fn table(&self) -> Rc<RefCell<SymbolTable>> {
+        match self {
+            TableLink::Strong(table) => table.clone(),
+            // a weak link only sits in the table it points to, so that table is still around
+            TableLink::Weak(table) => table.upgrade().unwrap(),
+        }
+    }
+
+    fn stored_in(self, table: &SymbolTable) -> Self {
+        match self {
+            TableLink::Strong(linked) if ptr::eq(linked.as_ptr(), table) => {
+                TableLink::Weak(Rc::downgrade(&linked))
+            }
+            link => link,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Function {
+    pub id: usize,
     pub name: String,
     pub body_node: Box<AstNode>,
     pub arg_names: Arc<[String]>,
+    pub arg_defaults: Arc<[Option<Box<AstNode>>]>,
+    pub is_variadic: bool,
     pub should_auto_return: bool,
-    pub context: Option<Rc<RefCell<Context>>>,
+    // the scope the function was defined in, kept alive so closures still see it after it ends
+    pub captured_env: Option<TableLink>,
+    // the global table of the interpreter that defined it, reused by every call
+    pub global_symbol_table: Option<TableLink>,
+    pub context: Option<Weak<RefCell<Context>>>,
     pub pos_start: Option<Position>,
     pub pos_end: Option<Position>,
 }
@@ -27,43 +73,111 @@ impl Function {
         name: String,
         body_node: Box<AstNode>,
         arg_names: &[String],
+        arg_defaults: &[Option<Box<AstNode>>],
+        is_variadic: bool,
         should_auto_return: bool,
+        captured_env: Option<Rc<RefCell<SymbolTable>>>,
     ) -> Self {
         Self {
+            id: NEXT_FUNCTION_ID.fetch_add(1, Ordering::Relaxed),
             name,
             body_node,
             arg_names: Arc::from(arg_names),
+            arg_defaults: Arc::from(arg_defaults),
+            is_variadic,
             should_auto_return,
+            captured_env: captured_env.map(TableLink::Strong),
+            global_symbol_table: None,
             context: None,
             pos_start: None,
             pos_end: None,
         }
     }
 
+    pub fn with_globals(mut self, global_symbol_table: Rc<RefCell<SymbolTable>>) -> Self {
+        self.global_symbol_table = Some(TableLink::Strong(global_symbol_table));
+        self
+    }
+
+    // the copy of the function that 'table' keeps, linking back to that table weakly
+    pub fn stored_in(mut self, table: &SymbolTable) -> Self {
+        self.captured_env = self.captured_env.map(|link| link.stored_in(table));
+        self.global_symbol_table = self.global_symbol_table.map(|link| link.stored_in(table));
+        self
+    }
+
+    // a copy read out of a table, holding on to everything it links to again
+    pub fn taken_out(mut self) -> Self {
+        self.captured_env = self.captured_env.map(|link| TableLink::Strong(link.table()));
+        self.global_symbol_table =
+            self.global_symbol_table.map(|link| TableLink::Strong(link.table()));
+        self
+    }
+
+    // calls run in an interpreter sharing the defining one's globals, so they don't
+    // rebuild the table of built-ins every time
+    pub fn interpreter(&self, caller: &Interpreter) -> Interpreter {
+        match &self.global_symbol_table {
+            Some(global_symbol_table) => Interpreter::with_globals(global_symbol_table.table()),
+            None => Interpreter::new(),
+        }
+        .called_from(caller)
+    }
+
+    // resolve names where the function was defined so a module's private helpers stay reachable
+    pub fn defining_symbol_table(&self) -> Rc<RefCell<SymbolTable>> {
+        match &self.captured_env {
+            Some(link) => link.table(),
+            None => self
+                .defining_context()
+                .unwrap()
+                .borrow()
+                .symbol_table
+                .as_ref()
+                .unwrap()
+                .clone(),
+        }
+    }
+
+    // values only hold their context weakly, a call made after it's gone has no parent to list
+    pub fn defining_context(&self) -> Option<Rc<RefCell<Context>>> {
+        self.context.as_ref().and_then(Weak::upgrade)
+    }
+
     pub fn generate_new_context(&self) -> Rc<RefCell<Context>> {
-        let mut new_context = Context::new(
-            self.name.clone(),
-            Some(self.context.as_ref().unwrap().clone()),
-            self.pos_start.clone(),
-        );
-        let parent_st = self
-            .context
-            .as_ref()
-            .unwrap()
-            .borrow()
-            .symbol_table
-            .as_ref()
-            .unwrap()
-            .clone();
+        let mut new_context =
+            Context::new(self.name.clone(), self.defining_context(), self.pos_start.clone());
+        let parent_st = self.defining_symbol_table();
         new_context.symbol_table = Some(Rc::new(RefCell::new(SymbolTable::new(Some(parent_st)))));
 
         Rc::new(RefCell::new(new_context))
     }
 
+    // default argument values are evaluated in the scope of the definition, not the call
+    pub fn generate_defaults_context(&self) -> Rc<RefCell<Context>> {
+        let mut defaults_context =
+            Context::new(self.name.clone(), self.defining_context(), self.pos_start.clone());
+        defaults_context.symbol_table = Some(self.defining_symbol_table());
+
+        Rc::new(RefCell::new(defaults_context))
+    }
+
     pub fn check_args(&self, arg_names: &[String], args: &[Value]) -> RuntimeResult {
         let mut result = RuntimeResult::new();
+        let defaults = self.arg_defaults.iter().filter(|default| default.is_some()).count();
+        // the rest argument is never required, it's an empty list when nothing is left for it
+        let positional = arg_names.len() - usize::from(self.is_variadic);
+        let required = positional.saturating_sub(defaults);
+
+        if (args.len() > positional && !self.is_variadic) || args.len() < required {
+            let expected = if self.is_variadic {
+                format!("at least {required}")
+            } else if required == positional {
+                required.to_string()
+            } else {
+                format!("{required} to {positional}")
+            };
 
-        if args.len() > arg_names.len() || args.len() < arg_names.len() {
             return result.failure(Some(StandardError::new(
                 "invalid function call",
                 self.pos_start.as_ref().unwrap().clone(),
@@ -72,7 +186,7 @@ impl Function {
                     format!(
                         "{} takes {} positional argument(s) but the program gave {}",
                         self.name,
-                        arg_names.len(),
+                        expected,
                         args.len()
                     )
                     .as_str(),
@@ -88,10 +202,43 @@ impl Function {
         arg_names: &[String],
         args: &[Value],
         expr_ctx: Rc<RefCell<Context>>,
-    ) {
-        for i in 0..args.len() {
-            let arg_name = arg_names[i].clone();
-            let mut arg_value = args[i].clone();
+        caller: &Interpreter,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let rest_index = if self.is_variadic { arg_names.len() - 1 } else { arg_names.len() };
+
+        for (i, arg_name) in arg_names.iter().enumerate() {
+            let mut arg_value = match args.get(i) {
+                _ if i == rest_index => {
+                    let rest = args.get(i..).unwrap_or_default();
+
+                    if let (Some(first), Some(last)) = (rest.first(), rest.last()) {
+                        if let Err(error) = caller.limits.check_list_length(
+                            rest.len(),
+                            first.position_start().unwrap(),
+                            last.position_end().unwrap(),
+                        ) {
+                            return result.failure(Some(error));
+                        }
+                    }
+
+                    Value::ListValue(List::new(rest.to_vec()))
+                }
+                Some(arg) => arg.clone(),
+                // check_args made sure every argument left out has a default
+                None => {
+                    let default_node = self.arg_defaults[i].clone().unwrap();
+                    let default_value = result.register(
+                        self.interpreter(caller).visit(default_node, self.generate_defaults_context()),
+                    );
+
+                    if result.should_return() {
+                        return result;
+                    }
+
+                    default_value.unwrap()
+                }
+            };
             arg_value.set_context(Some(expr_ctx.clone()));
 
             expr_ctx
@@ -102,6 +249,8 @@ impl Function {
                 .borrow_mut()
                 .set(arg_name.to_string(), Some(arg_value));
         }
+
+        result.success(None)
     }
 
     pub fn check_and_populate_args(
@@ -109,6 +258,7 @@ impl Function {
         arg_names: &[String],
         args: &[Value],
         expr_ctx: Rc<RefCell<Context>>,
+        caller: &Interpreter,
     ) -> RuntimeResult {
         let mut result = RuntimeResult::new();
         result.register(self.check_args(arg_names, args));
@@ -117,24 +267,54 @@ impl Function {
             return result;
         }
 
-        self.populate_args(arg_names, args, expr_ctx);
+        result.register(self.populate_args(arg_names, args, expr_ctx, caller));
+
+        if result.should_return() {
+            return result;
+        }
 
         result.success(None)
     }
 
-    pub fn execute(&self, args: &[Value]) -> RuntimeResult {
+    pub fn execute(&self, args: &[Value], caller: &Interpreter) -> RuntimeResult {
         let mut result = RuntimeResult::new();
-        let mut interpreter = Interpreter::new();
+
+        if caller.call_depth >= MAX_CALL_DEPTH {
+            return result.failure(Some(StandardError::new(
+                "too many calls inside each other",
+                self.pos_start.as_ref().unwrap().clone(),
+                self.pos_end.as_ref().unwrap().clone(),
+                Some(
+                    format!("calls can go {MAX_CALL_DEPTH} deep, check for recursion that never ends")
+                        .as_str(),
+                ),
+            )));
+        }
+
+        let mut interpreter = self.interpreter(caller);
         let exec_context = self.generate_new_context();
 
-        result.register(self.check_and_populate_args(&self.arg_names, args, exec_context.clone()));
+        result.register(self.check_and_populate_args(
+            &self.arg_names,
+            args,
+            exec_context.clone(),
+            caller,
+        ));
 
         if result.should_return() {
             return result;
         }
 
-        let value =
-            result.register(interpreter.visit(self.body_node.clone(), exec_context.clone()));
+        let mut body_result = interpreter.visit(self.body_node.clone(), exec_context.clone());
+
+        // the innermost call claims the error, the callers are found through its context
+        if let Some(error) = body_result.error.as_mut() {
+            if error.traceback.call_stack.is_empty() {
+                error.set_call_stack(&exec_context);
+            }
+        }
+
+        let value = result.register(body_result);
 
         if result.should_return() && result.func_return_value.is_none() {
             return result;
@@ -142,7 +322,7 @@ impl Function {
 
         let return_value = if self.should_auto_return { value } else { None }
             .or(result.func_return_value.clone())
-            .or(Some(Number::null_value()));
+            .or(Some(Value::NullValue(Null::new())));
 
         result.success(return_value)
     }
@@ -2,14 +2,19 @@ use crate::{
     errors::standard_error::StandardError,
     interpreting::context::Context,
     lexing::position::Position,
-    values::{number::Number, value::Value},
+    values::{bool::Bool, value::Value},
+};
+use std::{
+    cell::RefCell,
+    cmp::Ordering,
+    iter::zip,
+    rc::Weak,
 };
-use std::{cell::RefCell, iter::zip, rc::Rc};
 
 #[derive(Debug, Clone)]
 pub struct List {
     pub elements: Vec<Value>,
-    pub context: Option<Rc<RefCell<Context>>>,
+    pub context: Option<Weak<RefCell<Context>>>,
     pub pos_start: Option<Position>,
     pub pos_end: Option<Position>,
 }
@@ -36,78 +41,60 @@ impl List {
                 "+" => {
                     Ok(self.append(&mut right.elements.clone()))
                 }
-                "==" => {
-                    let mut is_eq = Number::null_value();
+                // equal when both hold the same number of elements and each pair is equal
+                "==" | "!=" => {
+                    let mut is_eq = self.elements.len() == right.elements.len();
 
                     for (a, b) in zip(&self.elements, &right.elements) {
-                        let result = a.to_owned().perform_operation("==", b.to_owned());
-
-                        if result.is_err() {
-                            return Err(result.err().unwrap());
+                        if !is_eq {
+                            break;
                         }
 
-                        is_eq = result.ok().unwrap();
+                        // elements that can't be compared, like those of different types, differ
+                        is_eq = a
+                            .to_owned()
+                            .perform_operation("==", b.to_owned())
+                            .is_ok_and(|is_eq| is_eq.is_true());
                     }
 
-                    Ok(is_eq.set_context(self.context.clone()))
+                    Ok(Bool::from(is_eq == (operator == "=="))
+                        .set_weak_context(self.context.clone()))
                 }
-                "!=" => {
-                    let mut is_neq = Number::null_value();
-
-                    for (a, b) in zip(&self.elements, &right.elements) {
-                        let result = a.to_owned().perform_operation("!=", b.to_owned());
-
-                        if result.is_err() {
-                            return Err(result.err().unwrap());
+                "<" | ">" | "<=" | ">=" => {
+                    let ordering = match Self::compare(&self.elements, &right.elements) {
+                        Some(ordering) => ordering,
+                        None => {
+                            return Err(StandardError::new(
+                                "cannot order lists holding different types",
+                                self.pos_start.as_ref().unwrap().clone(),
+                                other.position_end().unwrap(),
+                                Some("only numbers, strings and lists of them can be ordered"),
+                            ));
                         }
+                    };
 
-                        is_neq = result.ok().unwrap();
-                    }
+                    let is_true = match operator {
+                        "<" => ordering.is_lt(),
+                        ">" => ordering.is_gt(),
+                        "<=" => ordering.is_le(),
+                        _ => ordering.is_ge(),
+                    };
 
-                    Ok(is_neq.set_context(self.context.clone()))
-                }
-                "and" => {
-                    Ok(Value::NumberValue(Number::new(
-                        (!self.elements.is_empty() && !right.elements.is_empty()) as u8 as f64,
-                    ))
-                    .set_context(self.context.clone()))
-                }
-                "or" => {
-                    Ok(Value::NumberValue(Number::new(
-                        (!self.elements.is_empty() || !right.elements.is_empty()) as u8 as f64,
-                    ))
-                    .set_context(self.context.clone()))
+                    Ok(Bool::from(is_true).set_weak_context(self.context.clone()))
                 }
                 _ => Err(self.illegal_operation(Some(other))),
             },
             Value::NumberValue(ref right) => match operator {
-                "^" => {
-                    if right.value < -1.0 {
-                        return Err(StandardError::new(
-                            "cannot access a negative index",
-                            right.pos_start.clone().unwrap(),
-                            right.pos_end.clone().unwrap(),
-                            Some(
-                                "use an index greater than or equal to 0 or use -1 to reverse the list",
-                            ),
-                        ));
-                    }
-
-                    if right.value == -1.0 {
-                        return Ok(self.reverse());
-                    }
-
-                    if (right.value as usize) >= self.elements.len() {
-                        return Err(StandardError::new(
-                            "index is out of bounds",
-                            right.pos_start.clone().unwrap(),
-                            right.pos_end.clone().unwrap(),
-                            None,
-                        ));
-                    }
-
-                    Ok(self.retrieve(right.value as usize))
-                }
+                // negative indices count back from the end, so -1 is the last element
+                "^" => match right.index_into(self.elements.len())? {
+                    Some(index) => Ok(self.retrieve(index)),
+                    None => Err(StandardError::new(
+                        "index is out of bounds",
+                        right.pos_start.clone().unwrap(),
+                        right.pos_end.clone().unwrap(),
+                        Some(format!("the list has {} element(s)", self.elements.len()).as_str()),
+                    )),
+                },
                 "-" => {
                     if right.value < 0.0 {
                         return Err(StandardError::new(
@@ -118,7 +105,9 @@ impl List {
                         ));
                     }
 
-                    if (right.value as usize) >= self.elements.len() {
+                    let index = right.as_index()?;
+
+                    if index >= self.elements.len() {
                         return Err(StandardError::new(
                             "index is out of bounds",
                             right.pos_start.clone().unwrap(),
@@ -127,7 +116,7 @@ impl List {
                         ));
                     }
 
-                    Ok(self.remove(right.value as usize))
+                    Ok(self.remove(index))
                 }
                 _ => Err(self.illegal_operation(Some(other))),
             },
@@ -150,14 +139,35 @@ impl List {
         )
     }
 
+    // lexicographic, element by element with the shorter list first when one is a prefix
+    // of the other. 'None' when two elements can't be ordered against each other
+    fn compare(left: &[Value], right: &[Value]) -> Option<Ordering> {
+        for (a, b) in zip(left, right) {
+            let ordering = match (a, b) {
+                (Value::NumberValue(a), Value::NumberValue(b)) => a.value.partial_cmp(&b.value)?,
+                (Value::StringValue(a), Value::StringValue(b)) => a.value.cmp(&b.value),
+                (Value::ListValue(a), Value::ListValue(b)) => {
+                    Self::compare(&a.elements, &b.elements)?
+                }
+                _ => return None,
+            };
+
+            if ordering.is_ne() {
+                return Some(ordering);
+            }
+        }
+
+        Some(left.len().cmp(&right.len()))
+    }
+
     pub fn push(mut self, item: Value) -> Value {
         self.elements.push(item);
 
         Value::ListValue(self)
     }
 
-    pub fn append(mut self, other: &mut Vec<Value>) -> Value {
-        self.elements.append(other);
+    pub fn append(mut self, items: &mut Vec<Value>) -> Value {
+        self.elements.append(items);
 
         Value::ListValue(self)
     }
@@ -0,0 +1,109 @@
+use crate::{
+    errors::standard_error::StandardError,
+    interpreting::context::Context,
+    lexing::position::Position,
+    values::{bool::Bool, value::Value},
+};
+use std::{cell::RefCell, collections::HashMap, rc::Weak};
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    pub entries: HashMap<String, Value>,
+    pub context: Option<Weak<RefCell<Context>>>,
+    pub pos_start: Option<Position>,
+    pub pos_end: Option<Position>,
+}
+
+impl Map {
+    pub fn new(entries: HashMap<String, Value>) -> Self {
+        Self {
+            entries,
+            context: None,
+            pos_start: None,
+            pos_end: None,
+        }
+    }
+
+    pub fn from(entries: HashMap<String, Value>) -> Value {
+        Value::MapValue(Map::new(entries))
+    }
+
+    pub fn perform_operation(&self, operator: &str, other: Value) -> Result<Value, StandardError> {
+        match operator {
+            "==" | "!=" => {
+                let is_eq = match other {
+                    Value::MapValue(ref right) => self.equals(right),
+                    _ => false,
+                };
+
+                Ok(Bool::from(is_eq == (operator == "==")).set_weak_context(self.context.clone()))
+            }
+            "^" => match other {
+                Value::StringValue(ref key) => match self.entries.get(&key.value) {
+                    Some(value) => Ok(value.clone()),
+                    None => Err(StandardError::new(
+                        format!("key '{}' doesn't exist", key.value).as_str(),
+                        other.position_start().unwrap(),
+                        other.position_end().unwrap(),
+                        Some(self.key_help().as_str()),
+                    )),
+                },
+                _ => Err(self.illegal_operation(Some(other))),
+            },
+            _ => Err(self.illegal_operation(Some(other))),
+        }
+    }
+
+    // same keys, and every value compares equal with '=='
+    pub fn equals(&self, other: &Map) -> bool {
+        self.entries.len() == other.entries.len()
+            && self.entries.iter().all(|(key, value)| {
+                other.entries.get(key).is_some_and(|other_value| {
+                    value
+                        .to_owned()
+                        .perform_operation("==", other_value.to_owned())
+                        .is_ok_and(|is_eq| is_eq.is_true())
+                })
+            })
+    }
+
+    pub fn key_help(&self) -> String {
+        let mut keys: Vec<&String> = self.entries.keys().collect();
+        keys.sort();
+
+        if keys.is_empty() {
+            return "the map is empty".to_string();
+        }
+
+        format!(
+            "use one of the map's keys: {}",
+            keys.iter().map(|key| format!("'{key}'")).collect::<Vec<_>>().join(", ")
+        )
+    }
+
+    pub fn illegal_operation(&self, other: Option<Value>) -> StandardError {
+        StandardError::new(
+            "operation not supported by type",
+            self.pos_start.as_ref().unwrap().clone(),
+            // unary operators hand over a placeholder value that has no position
+            other
+                .and_then(|other| other.position_end())
+                .unwrap_or_else(|| self.pos_end.as_ref().unwrap().clone()),
+            None,
+        )
+    }
+
+    pub fn as_string(&self) -> String {
+        // sort the keys so printing a map is deterministic
+        let mut keys: Vec<&String> = self.entries.keys().collect();
+        keys.sort();
+
+        let output = keys
+            .iter()
+            .map(|key| format!("{key}: {}", self.entries[*key].as_string()))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!("{{{output}}}")
+    }
+}
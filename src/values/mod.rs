@@ -1,6 +1,11 @@
+pub mod bool;
 pub mod built_in_function;
 pub mod function;
 pub mod list;
+pub mod map;
+pub mod null;
 pub mod number;
 pub mod string;
+pub mod struct_def;
+pub mod struct_instance;
 pub mod value;
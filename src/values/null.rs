@@ -0,0 +1,53 @@
+use std::{cell::RefCell, rc::Weak};
+
+use crate::{
+    errors::standard_error::StandardError,
+    interpreting::context::Context,
+    lexing::position::Position,
+    values::{bool::Bool, value::Value},
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct Null {
+    pub context: Option<Weak<RefCell<Context>>>,
+    pub pos_start: Option<Position>,
+    pub pos_end: Option<Position>,
+}
+
+impl Null {
+    pub fn new() -> Self {
+        Self {
+            context: None,
+            pos_start: None,
+            pos_end: None,
+        }
+    }
+
+    pub fn perform_operation(&self, operator: &str, other: Value) -> Result<Value, StandardError> {
+        let is_null = matches!(other, Value::NullValue(_));
+
+        let result = match operator {
+            "==" => is_null,
+            "!=" => !is_null,
+            _ => return Err(self.illegal_operation(Some(other))),
+        };
+
+        Ok(Bool::from(result).set_weak_context(self.context.clone()))
+    }
+
+    pub fn illegal_operation(&self, other: Option<Value>) -> StandardError {
+        StandardError::new(
+            "operation not supported by the null type",
+            self.pos_start.as_ref().unwrap().clone(),
+            // unary operators hand over a placeholder value that has no position
+            other
+                .and_then(|other| other.position_end())
+                .unwrap_or_else(|| self.pos_end.as_ref().unwrap().clone()),
+            Some("null only supports 'and', 'or', 'not', '==' and '!='"),
+        )
+    }
+
+    pub fn as_string(&self) -> String {
+        "null".to_string()
+    }
+}
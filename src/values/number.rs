@@ -1,14 +1,15 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, rc::Weak};
 
 use crate::{
     errors::standard_error::StandardError, interpreting::context::Context,
-    lexing::position::Position, values::value::Value,
+    lexing::position::Position,
+    values::{bool::Bool, value::Value},
 };
 
 #[derive(Debug, Clone)]
 pub struct Number {
     pub value: f64,
-    pub context: Option<Rc<RefCell<Context>>>,
+    pub context: Option<Weak<RefCell<Context>>>,
     pub pos_start: Option<Position>,
     pub pos_end: Option<Position>,
 }
@@ -27,18 +28,6 @@ impl Number {
         Value::NumberValue(Number::new(value))
     }
 
-    pub fn null_value() -> Value {
-        Value::NumberValue(Number::new(0.0))
-    }
-
-    pub fn true_value() -> Value {
-        Value::NumberValue(Number::new(1.0))
-    }
-
-    pub fn false_value() -> Value {
-        Value::NumberValue(Number::new(0.0))
-    }
-
     pub fn perform_operation(&self, operator: &str, other: Value) -> Result<Value, StandardError> {
         match other {
             Value::NumberValue(ref right) => {
@@ -46,9 +35,9 @@ impl Number {
                 let right_val = right.value;
 
                 let result = match operator {
-                    "+" => Some(left_val + right_val),
-                    "-" => Some(left_val - right_val),
-                    "*" => Some(left_val * right_val),
+                    "+" => left_val + right_val,
+                    "-" => left_val - right_val,
+                    "*" => left_val * right_val,
                     "/" => {
                         if right_val == 0.0 {
                             return Err(StandardError::new(
@@ -58,47 +47,56 @@ impl Number {
                                 None,
                             ));
                         }
-                        Some(left_val / right_val)
+                        left_val / right_val
                     }
                     "^" => {
-                        if right_val <= 0.0 {
+                        if left_val == 0.0 && right_val < 0.0 {
                             return Err(StandardError::new(
-                                "powered by operator less than or equal to 0",
+                                "cannot raise 0 to a negative power",
                                 right.pos_start.clone().unwrap(),
                                 right.pos_end.clone().unwrap(),
-                                None,
+                                Some("a negative power of 0 would divide by 0"),
                             ));
                         }
 
-                        Some(left_val.powf(right_val))
+                        left_val.powf(right_val)
                     }
                     "%" => {
-                        if right_val <= 0.0 {
+                        if right_val == 0.0 {
                             return Err(StandardError::new(
-                                "modded by operator less than or equal to 0",
+                                "modulo by zero",
                                 right.pos_start.clone().unwrap(),
                                 right.pos_end.clone().unwrap(),
                                 None,
                             ));
                         }
 
-                        Some(left_val.rem_euclid(right_val))
+                        left_val.rem_euclid(right_val)
+                    }
+                    _ => {
+                        let is_true = match operator {
+                            "==" => left_val == right_val,
+                            "!=" => left_val != right_val,
+                            "<" => left_val < right_val,
+                            ">" => left_val > right_val,
+                            "<=" => left_val <= right_val,
+                            ">=" => left_val >= right_val,
+                            _ => return Err(self.illegal_operation(Some(other))),
+                        };
+
+                        return Ok(Bool::from(is_true).set_weak_context(self.context.clone()));
                     }
-                    "==" => Some((left_val == right_val) as u8 as f64),
-                    "!=" => Some((left_val != right_val) as u8 as f64),
-                    "<" => Some((left_val < right_val) as u8 as f64),
-                    ">" => Some((left_val > right_val) as u8 as f64),
-                    "<=" => Some((left_val <= right_val) as u8 as f64),
-                    ">=" => Some((left_val >= right_val) as u8 as f64),
-                    "and" => Some(((left_val != 0.0) && (right_val != 0.0)) as u8 as f64),
-                    "or" => Some(((left_val != 0.0) || (right_val != 0.0)) as u8 as f64),
-                    "not" => Some(if self.value == 0.0 { 1.0 } else { 0.0 }),
-                    _ => return Err(self.illegal_operation(Some(other))),
                 };
 
-                Ok(Value::NumberValue(Number::new(result.unwrap()))
-                    .set_context(self.context.clone()))
+                Ok(Value::NumberValue(Number::new(result)).set_weak_context(self.context.clone()))
             }
+            // only a string on the left side turns the other side into text
+            Value::StringValue(_) if operator == "+" => Err(StandardError::new(
+                "cannot add a string to a number",
+                self.pos_start.as_ref().unwrap().clone(),
+                other.position_end().unwrap(),
+                Some("start with the string or use tostring() on the number"),
+            )),
             _ => Err(self.illegal_operation(Some(other))),
         }
     }
@@ -116,7 +114,52 @@ impl Number {
         )
     }
 
+    // indices and counts must be whole and not negative, casting NaN or infinity
+    // straight to a usize would quietly turn them into 0 or usize::MAX
+    pub fn as_index(&self) -> Result<usize, StandardError> {
+        if !self.value.is_finite() || self.value.fract() != 0.0 {
+            return Err(StandardError::new(
+                "expected a whole number",
+                self.pos_start.clone().unwrap(),
+                self.pos_end.clone().unwrap(),
+                Some("indices and counts can't be fractional, NaN or infinite"),
+            ));
+        }
+
+        if self.value < 0.0 {
+            return Err(StandardError::new(
+                "expected a number greater than or equal to 0",
+                self.pos_start.clone().unwrap(),
+                self.pos_end.clone().unwrap(),
+                None,
+            ));
+        }
+
+        Ok(self.value as usize)
+    }
+
+    // an index into `length` elements, negative ones counting back from the end so -1 is
+    // the last. Ok(None) when it's a fine index that just falls outside them
+    pub fn index_into(&self, length: usize) -> Result<Option<usize>, StandardError> {
+        if self.value < 0.0 {
+            let mut distance = self.clone();
+            distance.value = -self.value;
+
+            return Ok(length.checked_sub(distance.as_index()?));
+        }
+
+        let index = self.as_index()?;
+
+        Ok((index < length).then_some(index))
+    }
+
+    // f64's Display already leaves off a trailing '.0' and, unlike casting to an integer,
+    // doesn't clamp huge numbers. only negative zero needs tidying up
     pub fn as_string(&self) -> String {
+        if self.value == 0.0 {
+            return "0".to_string();
+        }
+
         self.value.to_string()
     }
 }
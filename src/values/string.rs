@@ -1,16 +1,16 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, rc::Weak};
 
 use crate::{
     errors::standard_error::StandardError,
     interpreting::context::Context,
     lexing::position::Position,
-    values::{number::Number, value::Value},
+    values::{bool::Bool, value::Value},
 };
 
 #[derive(Debug, Clone)]
 pub struct Str {
     pub value: String,
-    pub context: Option<Rc<RefCell<Context>>>,
+    pub context: Option<Weak<RefCell<Context>>>,
     pub pos_start: Option<Position>,
     pub pos_end: Option<Position>,
 }
@@ -36,12 +36,7 @@ impl Str {
     ) -> Result<Value, StandardError> {
         match other {
             Value::StringValue(ref value) => match operator {
-                "+" => {
-                    let mut copy = self.clone();
-                    copy.value.push_str(&value.value);
-
-                    Ok(Value::StringValue(copy))
-                }
+                "+" => Ok(self.concat(&value.value)),
                 "-" => {
                     let mut copy = self.clone();
                     copy.value.clear();
@@ -50,32 +45,16 @@ impl Str {
                     Ok(Value::StringValue(copy))
                 }
                 "==" => {
-                    Ok(Value::NumberValue(Number::new(
-                        (self.value == value.value) as u8 as f64,
-                    ))
-                    .set_context(self.context.clone()))
+                    Ok(Bool::from(self.value == value.value).set_weak_context(self.context.clone()))
                 }
                 "!=" => {
-                    Ok(Value::NumberValue(Number::new(
-                        (self.value != value.value) as u8 as f64,
-                    ))
-                    .set_context(self.context.clone()))
-                }
-                "and" => {
-                    Ok(Value::NumberValue(Number::new(
-                        (!self.value.is_empty() && !value.value.is_empty()) as u8 as f64,
-                    ))
-                    .set_context(self.context.clone()))
-                }
-                "or" => {
-                    Ok(Value::NumberValue(Number::new(
-                        (!self.value.is_empty() || !value.value.is_empty()) as u8 as f64,
-                    ))
-                    .set_context(self.context.clone()))
+                    Ok(Bool::from(self.value != value.value).set_weak_context(self.context.clone()))
                 }
                 _ => Err(self.illegal_operation(Some(&other))),
             },
             Value::NumberValue(ref value) => match operator {
+                // a string on the left turns numbers and booleans into text
+                "+" => Ok(self.concat(&value.as_string())),
                 "*" => {
                     if value.value < 0.0 {
                         return Err(StandardError::new(
@@ -86,53 +65,64 @@ impl Str {
                         ));
                     }
 
-                    let mut copy = self.clone();
-                    copy.value = self.value.repeat(value.value as usize);
-
-                    Ok(Value::StringValue(copy))
-                }
-                "^" => {
-                    if value.value < -1.0 {
+                    if !value.value.is_finite() {
                         return Err(StandardError::new(
-                            "cannot access a negative index",
-                            value.pos_start.clone().unwrap(),
-                            value.pos_end.clone().unwrap(),
-                            Some(
-                                "use an index greater than or equal to 0 or use -1 to reverse the string",
-                            ),
+                            "cannot multiply string by an infinite value",
+                            other.position_start().unwrap(),
+                            other.position_end().unwrap(),
+                            Some("repeat the string a finite number of times"),
                         ));
                     }
 
-                    if value.value == -1.0 {
-                        return Ok(Str::from(
-                            self.value.chars().rev().collect::<String>().as_str(),
+                    if value.value.fract() != 0.0 {
+                        return Err(StandardError::new(
+                            "cannot multiply string by a fractional value",
+                            other.position_start().unwrap(),
+                            other.position_end().unwrap(),
+                            Some("repeat the string a whole number of times"),
                         ));
                     }
 
-                    if (value.value as usize) >= self.value.len() {
-                        return Err(StandardError::new(
+                    let mut copy = self.clone();
+                    copy.value = self.value.repeat(value.as_index()?);
+
+                    Ok(Value::StringValue(copy))
+                }
+                "^" => {
+                    let length = self.value.chars().count();
+
+                    // indices count characters, not bytes, so multibyte text works. negative
+                    // ones count back from the end like they do for lists, so -1 is the last
+                    match value.index_into(length)?.and_then(|index| self.value.chars().nth(index)) {
+                        Some(character) => Ok(Str::from(character.to_string().as_str())),
+                        None => Err(StandardError::new(
                             "index is out of bounds",
                             value.pos_start.clone().unwrap(),
                             value.pos_end.clone().unwrap(),
-                            None,
-                        ));
+                            Some(format!("the string has {length} character(s)").as_str()),
+                        )),
                     }
-
-                    Ok(Str::from(
-                        self.value
-                            .chars()
-                            .nth(value.value as usize)
-                            .unwrap()
-                            .to_string()
-                            .as_str(),
-                    ))
                 }
                 _ => Err(self.illegal_operation(Some(&other))),
             },
+            Value::BoolValue(ref value) if operator == "+" => Ok(self.concat(&value.as_string())),
+            _ if operator == "+" => Err(StandardError::new(
+                format!("cannot add a {} to a string", other.object_type()).as_str(),
+                self.pos_start.as_ref().unwrap().clone(),
+                other.position_end().unwrap(),
+                Some("use tostring() to turn the value into a string first"),
+            )),
             _ => Err(self.illegal_operation(Some(&other))),
         }
     }
 
+    fn concat(&self, text: &str) -> Value {
+        let mut copy = self.clone();
+        copy.value.push_str(text);
+
+        Value::StringValue(copy)
+    }
+
     pub fn illegal_operation(&self, other: Option<&Value>) -> StandardError {
         StandardError::new(
             "operation not supported by the string type",
@@ -0,0 +1,64 @@
+use std::{cell::RefCell, collections::HashMap, rc::Weak, sync::Arc};
+
+use crate::{
+    errors::standard_error::StandardError,
+    interpreting::{context::Context, runtime_result::RuntimeResult},
+    lexing::position::Position,
+    values::{struct_instance::StructInstance, value::Value},
+};
+
+#[derive(Debug, Clone)]
+pub struct StructDef {
+    pub name: String,
+    pub field_names: Arc<[String]>,
+    pub context: Option<Weak<RefCell<Context>>>,
+    pub pos_start: Option<Position>,
+    pub pos_end: Option<Position>,
+}
+
+impl StructDef {
+    pub fn new(name: String, field_names: &[String]) -> Self {
+        Self {
+            name,
+            field_names: Arc::from(field_names),
+            context: None,
+            pos_start: None,
+            pos_end: None,
+        }
+    }
+
+    // calling a struct fills its fields in the order they were declared
+    pub fn execute(&self, args: &[Value]) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+
+        if args.len() != self.field_names.len() {
+            return result.failure(Some(StandardError::new(
+                "invalid struct call",
+                self.pos_start.as_ref().unwrap().clone(),
+                self.pos_end.as_ref().unwrap().clone(),
+                Some(
+                    format!(
+                        "{} takes {} field value(s) but the program gave {}",
+                        self.name,
+                        self.field_names.len(),
+                        args.len()
+                    )
+                    .as_str(),
+                ),
+            )));
+        }
+
+        let fields: HashMap<String, Value> =
+            self.field_names.iter().cloned().zip(args.iter().cloned()).collect();
+
+        result.success(Some(Value::StructInstance(StructInstance::new(
+            self.name.clone(),
+            self.field_names.clone(),
+            fields,
+        ))))
+    }
+
+    pub fn as_string(&self) -> String {
+        format!("struct: {}", self.name)
+    }
+}
@@ -0,0 +1,118 @@
+use std::{cell::RefCell, collections::HashMap, rc::Weak, sync::Arc};
+
+use crate::{
+    errors::standard_error::StandardError,
+    interpreting::context::Context,
+    lexing::position::Position,
+    values::{bool::Bool, value::Value},
+};
+
+#[derive(Debug, Clone)]
+pub struct StructInstance {
+    pub type_name: String,
+    // kept alongside the map so fields print in the order they were declared
+    pub field_names: Arc<[String]>,
+    pub fields: HashMap<String, Value>,
+    pub context: Option<Weak<RefCell<Context>>>,
+    pub pos_start: Option<Position>,
+    pub pos_end: Option<Position>,
+}
+
+impl StructInstance {
+    pub fn new(
+        type_name: String,
+        field_names: Arc<[String]>,
+        fields: HashMap<String, Value>,
+    ) -> Self {
+        Self {
+            type_name,
+            field_names,
+            fields,
+            context: None,
+            pos_start: None,
+            pos_end: None,
+        }
+    }
+
+    pub fn perform_operation(&self, operator: &str, other: Value) -> Result<Value, StandardError> {
+        match operator {
+            "==" | "!=" => {
+                let is_eq = match other {
+                    Value::StructInstance(ref right) => self.equals(right),
+                    _ => false,
+                };
+
+                Ok(Bool::from(is_eq == (operator == "==")).set_weak_context(self.context.clone()))
+            }
+            "^" => match other {
+                Value::StringValue(ref key) => match self.fields.get(&key.value) {
+                    Some(value) => Ok(value.clone()),
+                    None => Err(StandardError::new(
+                        self.missing_field(&key.value).as_str(),
+                        other.position_start().unwrap(),
+                        other.position_end().unwrap(),
+                        Some(self.field_help().as_str()),
+                    )),
+                },
+                _ => Err(self.illegal_operation(Some(other))),
+            },
+            _ => Err(self.illegal_operation(Some(other))),
+        }
+    }
+
+    // same struct, and every field compares equal with '=='
+    pub fn equals(&self, other: &StructInstance) -> bool {
+        self.type_name == other.type_name
+            && self.fields.iter().all(|(name, value)| {
+                other.fields.get(name).is_some_and(|other_value| {
+                    value
+                        .to_owned()
+                        .perform_operation("==", other_value.to_owned())
+                        .is_ok_and(|is_eq| is_eq.is_true())
+                })
+            })
+    }
+
+    pub fn missing_field(&self, name: &str) -> String {
+        format!("struct '{}' has no field '{name}'", self.type_name)
+    }
+
+    pub fn field_help(&self) -> String {
+        if self.field_names.is_empty() {
+            return format!("'{}' has no fields", self.type_name);
+        }
+
+        format!(
+            "use one of the fields of '{}': {}",
+            self.type_name,
+            self.field_names.iter().map(|name| format!("'{name}'")).collect::<Vec<_>>().join(", ")
+        )
+    }
+
+    pub fn illegal_operation(&self, other: Option<Value>) -> StandardError {
+        StandardError::new(
+            "operation not supported by type",
+            self.pos_start.as_ref().unwrap().clone(),
+            // unary operators hand over a placeholder value that has no position
+            other
+                .and_then(|other| other.position_end())
+                .unwrap_or_else(|| self.pos_end.as_ref().unwrap().clone()),
+            None,
+        )
+    }
+
+    pub fn as_string(&self) -> String {
+        let output = self
+            .field_names
+            .iter()
+            .map(|name| format!("{name}: {}", self.fields[name].as_string()))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        if output.is_empty() {
+            return format!("{} {{}}", self.type_name);
+        }
+
+        format!("{} {{ {output} }}", self.type_name)
+    }
+}
@@ -1,42 +1,58 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, mem, rc::{Rc, Weak}};
 
 use crate::{
     errors::standard_error::StandardError,
     interpreting::context::Context,
     lexing::position::Position,
     values::{
-        built_in_function::BuiltInFunction, function::Function, list::List, number::Number,
-        string::Str,
+        bool::Bool, built_in_function::BuiltInFunction, function::Function, list::List,
+        map::Map, null::Null, number::Number, string::Str, struct_def::StructDef,
+        struct_instance::StructInstance,
     },
 };
 
 #[derive(Debug, Clone)]
 pub enum Value {
     NumberValue(Number),
+    BoolValue(Bool),
+    NullValue(Null),
     ListValue(List),
+    MapValue(Map),
     StringValue(Str),
     FunctionValue(Function),
     BuiltInFunction(BuiltInFunction),
+    StructConstructor(StructDef),
+    StructInstance(StructInstance),
 }
 
 impl Value {
     pub fn position_start(&self) -> Option<Position> {
         match self {
             Value::NumberValue(value) => value.pos_start.clone(),
+            Value::BoolValue(value) => value.pos_start.clone(),
+            Value::NullValue(value) => value.pos_start.clone(),
             Value::ListValue(value) => value.pos_start.clone(),
+            Value::MapValue(value) => value.pos_start.clone(),
             Value::StringValue(value) => value.pos_start.clone(),
             Value::FunctionValue(value) => value.pos_start.clone(),
             Value::BuiltInFunction(value) => value.pos_start.clone(),
+            Value::StructConstructor(value) => value.pos_start.clone(),
+            Value::StructInstance(value) => value.pos_start.clone(),
         }
     }
 
     pub fn position_end(&self) -> Option<Position> {
         match self {
             Value::NumberValue(value) => value.pos_end.clone(),
+            Value::BoolValue(value) => value.pos_end.clone(),
+            Value::NullValue(value) => value.pos_end.clone(),
             Value::ListValue(value) => value.pos_end.clone(),
+            Value::MapValue(value) => value.pos_end.clone(),
             Value::StringValue(value) => value.pos_end.clone(),
             Value::FunctionValue(value) => value.pos_end.clone(),
             Value::BuiltInFunction(value) => value.pos_end.clone(),
+            Value::StructConstructor(value) => value.pos_end.clone(),
+            Value::StructInstance(value) => value.pos_end.clone(),
         }
     }
 
@@ -50,10 +66,22 @@ impl Value {
                 value.pos_start = pos_start;
                 value.pos_end = pos_end;
             }
+            Value::BoolValue(value) => {
+                value.pos_start = pos_start;
+                value.pos_end = pos_end;
+            }
+            Value::NullValue(value) => {
+                value.pos_start = pos_start;
+                value.pos_end = pos_end;
+            }
             Value::ListValue(value) => {
                 value.pos_start = pos_start;
                 value.pos_end = pos_end;
             }
+            Value::MapValue(value) => {
+                value.pos_start = pos_start;
+                value.pos_end = pos_end;
+            }
             Value::StringValue(value) => {
                 value.pos_start = pos_start;
                 value.pos_end = pos_end;
@@ -66,18 +94,36 @@ impl Value {
                 value.pos_start = pos_start;
                 value.pos_end = pos_end;
             }
+            Value::StructConstructor(value) => {
+                value.pos_start = pos_start;
+                value.pos_end = pos_end;
+            }
+            Value::StructInstance(value) => {
+                value.pos_start = pos_start;
+                value.pos_end = pos_end;
+            }
         }
 
         self.clone()
     }
 
+    // held weakly, a value stored in its own context's table would keep that context alive
     pub fn set_context(&mut self, context: Option<Rc<RefCell<Context>>>) -> Value {
+        self.set_weak_context(context.as_ref().map(Rc::downgrade))
+    }
+
+    pub fn set_weak_context(&mut self, context: Option<Weak<RefCell<Context>>>) -> Value {
         match self {
             Value::NumberValue(value) => value.context = context,
+            Value::BoolValue(value) => value.context = context,
+            Value::NullValue(value) => value.context = context,
             Value::ListValue(value) => value.context = context,
+            Value::MapValue(value) => value.context = context,
             Value::StringValue(value) => value.context = context,
             Value::FunctionValue(value) => value.context = context,
             Value::BuiltInFunction(value) => value.context = context,
+            Value::StructConstructor(value) => value.context = context,
+            Value::StructInstance(value) => value.context = context,
         }
 
         self.clone()
@@ -88,10 +134,37 @@ impl Value {
         operator: &str,
         other: Value,
     ) -> Result<Value, StandardError> {
+        // every value is either true or false, so the logical operators work on any two of them.
+        // 'not' ignores its right side, which is always a boolean
+        match operator {
+            "and" => return Ok(Bool::from(self.is_true() && other.is_true())),
+            "or" => return Ok(Bool::from(self.is_true() || other.is_true())),
+            "not" => return Ok(Bool::from(!self.is_true())),
+            _ => {}
+        }
+
+        // any value can be checked against any other, values of different types are never equal
+        if (operator == "==" || operator == "!=")
+            && mem::discriminant(self) != mem::discriminant(&other)
+        {
+            return Ok(Bool::from(operator == "!="));
+        }
+
         match self {
             Value::NumberValue(value) => value.perform_operation(operator, other),
+            Value::BoolValue(value) => value.perform_operation(operator, other),
+            Value::NullValue(value) => value.perform_operation(operator, other),
             Value::ListValue(value) => value.to_owned().perform_operation(operator, other),
+            Value::MapValue(value) => value.perform_operation(operator, other),
             Value::StringValue(value) => value.perform_operation(operator, other),
+            Value::StructInstance(value) => value.perform_operation(operator, other),
+            Value::FunctionValue(_) | Value::BuiltInFunction(_)
+                if operator == "==" || operator == "!=" =>
+            {
+                let is_eq = self.is_same_function(&other);
+
+                Ok(Bool::from(is_eq == (operator == "==")))
+            }
             _ => Err(StandardError::new(
                 format!("type doesn't support the '{operator}' operator").as_str(),
                 self.position_start().unwrap(),
@@ -101,13 +174,68 @@ impl Value {
         }
     }
 
+    // strings hold substrings, lists hold elements and maps hold keys,
+    // shared by `contains` and the 'in' operator
+    pub fn contains(&self, needle: &Value) -> Result<bool, StandardError> {
+        match (self, needle) {
+            (Value::StringValue(haystack), Value::StringValue(needle)) => {
+                Ok(haystack.as_string().contains(needle.as_string().as_str()))
+            }
+            (Value::StringValue(_), _) => Err(StandardError::new(
+                "expected type string",
+                needle.position_start().unwrap(),
+                needle.position_end().unwrap(),
+                Some("look for a string inside of a string"),
+            )),
+            // elements of another type than the needle simply don't match
+            (Value::ListValue(list), _) => Ok(list.elements.iter().any(|element| {
+                element
+                    .clone()
+                    .perform_operation("==", needle.clone())
+                    .is_ok_and(|is_eq| is_eq.is_true())
+            })),
+            (Value::MapValue(map), Value::StringValue(key)) => {
+                Ok(map.entries.contains_key(&key.value))
+            }
+            (Value::MapValue(_), _) => Err(StandardError::new(
+                "expected type string",
+                needle.position_start().unwrap(),
+                needle.position_end().unwrap(),
+                Some("map keys are always strings"),
+            )),
+            _ => Err(StandardError::new(
+                "expected type string, list or map",
+                self.position_start().unwrap(),
+                self.position_end().unwrap(),
+                Some("add the string, list or map you would like to search"),
+            )),
+        }
+    }
+
+    // functions are equal when they come from the same definition, built-ins when they share a name
+    pub fn is_same_function(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::FunctionValue(left), Value::FunctionValue(right)) => left.id == right.id,
+            (Value::BuiltInFunction(left), Value::BuiltInFunction(right)) => {
+                left.name == right.name
+            }
+            _ => false,
+        }
+    }
+
     pub fn object_type(&self) -> &str {
         match self {
             Value::NumberValue(_) => "number",
+            Value::BoolValue(_) => "boolean",
+            Value::NullValue(_) => "null",
             Value::ListValue(_) => "list",
+            Value::MapValue(_) => "map",
             Value::StringValue(_) => "string",
             Value::FunctionValue(_) => "function",
             Value::BuiltInFunction(_) => "built-in-function",
+            Value::StructConstructor(_) => "struct",
+            // an instance reports the name of its struct, like 'Point'
+            Value::StructInstance(value) => value.type_name.as_str(),
             _ => "null",
         }
     }
@@ -115,10 +243,13 @@ impl Value {
     pub fn is_true(&self) -> bool {
         match self {
             Value::NumberValue(value) => value.value != 0.0,
-            Value::ListValue(value) => value.elements.is_empty(),
-            Value::StringValue(value) => value.value.is_empty(),
-            Value::FunctionValue(value) => value.name.is_empty(),
-            Value::BuiltInFunction(value) => value.name.is_empty(),
+            Value::BoolValue(value) => value.value,
+            Value::NullValue(_) => false,
+            Value::ListValue(value) => !value.elements.is_empty(),
+            Value::MapValue(value) => !value.entries.is_empty(),
+            Value::StringValue(value) => !value.value.is_empty(),
+            Value::FunctionValue(_) | Value::BuiltInFunction(_) => true,
+            Value::StructConstructor(_) | Value::StructInstance(_) => true,
             _ => false,
         }
     }
@@ -126,10 +257,15 @@ impl Value {
     pub fn as_string(&self) -> String {
         match self {
             Value::NumberValue(value) => value.as_string(),
+            Value::BoolValue(value) => value.as_string(),
+            Value::NullValue(value) => value.as_string(),
             Value::ListValue(value) => value.as_string(),
+            Value::MapValue(value) => value.as_string(),
             Value::StringValue(value) => value.as_string(),
             Value::FunctionValue(value) => value.as_string(),
             Value::BuiltInFunction(value) => value.as_string(),
+            Value::StructConstructor(value) => value.as_string(),
+            Value::StructInstance(value) => value.as_string(),
             _ => "".to_string(),
         }
     }
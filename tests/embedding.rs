@@ -0,0 +1,136 @@
+use std::{env, error::Error, time::Duration};
+
+use maid_lang::{bench, on_large_stack, run, run_with_limits, Limits, StandardError};
+
+// the default library is fetched from MAID_STD on every run
+fn use_repo_library() {
+    env::set_var("MAID_STD", concat!(env!("CARGO_MANIFEST_DIR"), "/library"));
+}
+
+fn run_code(code: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    use_repo_library();
+    run("<stdin>", Some(code.to_string()))?;
+
+    Ok(())
+}
+
+#[test]
+fn clean_run_passes_through_question_mark() {
+    assert!(run_code("obj x = 1 + 2;").is_ok());
+}
+
+#[test]
+fn failed_run_passes_through_question_mark() {
+    let error = run_code("uhoh(\"broken\");").unwrap_err();
+
+    assert!(error.to_string().contains("broken"));
+}
+
+#[test]
+fn stray_bracket_is_a_syntax_error() {
+    let error = run_code("serve(1) }\nserve(2)").unwrap_err();
+
+    assert!(error.to_string().contains("expected operator or bracket"));
+}
+
+#[test]
+fn standard_error_converts_into_boxed_error() {
+    use_repo_library();
+
+    let error: StandardError = run("<stdin>", Some("1 / 0;".to_string()))
+        .unwrap_err()
+        .errors
+        .remove(0);
+    let boxed: Box<dyn Error> = Box::from(error.clone());
+    let sendable: Box<dyn Error + Send + Sync> = error.into();
+
+    assert!(boxed.to_string().contains("division by zero"));
+    assert_eq!(boxed.to_string(), sendable.to_string());
+}
+
+#[test]
+fn unreadable_file_is_an_error() {
+    use_repo_library();
+
+    let error = run("missing/no_such_file.maid", None).unwrap_err();
+
+    assert!(error.to_string().contains("failed to read"));
+}
+
+#[test]
+fn bench_fails_on_unreadable_file() {
+    use_repo_library();
+
+    assert!(!bench("missing/no_such_file.maid", 3, 1));
+}
+
+#[test]
+fn deep_nesting_and_recursion_run_without_overflowing() {
+    use_repo_library();
+
+    // runs this deep need more stack than a test thread has
+    let result = on_large_stack(|| {
+        run(concat!(env!("CARGO_MANIFEST_DIR"), "/library/tests/test_nesting.maid"), None)
+    });
+
+    assert!(result.is_ok(), "{}", result.unwrap_err());
+}
+
+#[test]
+fn operation_budget_ends_with_its_run() {
+    use_repo_library();
+
+    let code = "walk i = 0 through 100 { obj x = i * 2; }";
+    let limits = Limits {
+        max_operations: Some(50),
+        ..Limits::default()
+    };
+    let error = run_with_limits("<stdin>", Some(code.to_string()), limits).unwrap_err();
+
+    assert!(error.to_string().contains("operation budget exhausted"));
+    assert!(run("<stdin>", Some(code.to_string())).is_ok());
+}
+
+#[test]
+fn deadline_ends_with_its_run() {
+    use_repo_library();
+
+    let limits = Limits {
+        timeout: Some(Duration::ZERO),
+        ..Limits::default()
+    };
+    let error = run_with_limits("<stdin>", Some("obj x = 1;".to_string()), limits).unwrap_err();
+
+    assert!(error.to_string().contains("time limit exceeded"));
+    assert!(run("<stdin>", Some("obj x = 1;".to_string())).is_ok());
+}
+
+#[test]
+fn list_limit_ends_with_its_run() {
+    use_repo_library();
+
+    let code = "obj items = [1, 2, 3] * 4;";
+    let limits = Limits {
+        max_list_length: Some(3),
+        ..Limits::default()
+    };
+    let error = run_with_limits("<stdin>", Some(code.to_string()), limits).unwrap_err();
+
+    assert!(error.to_string().contains("list would grow past its limit"));
+    assert!(run("<stdin>", Some(code.to_string())).is_ok());
+}
+
+#[test]
+fn repeat_limit_ends_with_its_run() {
+    use_repo_library();
+
+    let code = "obj text = \"ab\" * 6;";
+    let limits = Limits {
+        max_repeat_length: Some(10),
+        ..Limits::default()
+    };
+    let error = run_with_limits("<stdin>", Some(code.to_string()), limits).unwrap_err();
+
+    assert!(error.to_string().contains("repeated string would be too long"));
+    assert!(run("<stdin>", Some(code.to_string())).is_ok());
+}
@@ -0,0 +1,35 @@
+use std::env;
+
+use maid_lang::run;
+
+// std_testing is fetched through the kennels list, and the default library from MAID_STD
+fn use_repo_library() {
+    env::set_var("MAID_STD", concat!(env!("CARGO_MANIFEST_DIR"), "/library"));
+    env::set_var("MAID_PKG", concat!(env!("CARGO_MANIFEST_DIR"), "/kennels"));
+}
+
+#[test]
+fn passing_suite_runs_cleanly() {
+    use_repo_library();
+
+    let result = run(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/library/tests/test_testing.maid"),
+        None,
+    );
+
+    assert!(result.is_ok(), "{}", result.unwrap_err());
+}
+
+#[test]
+fn failing_assert_eq_stops_the_run() {
+    use_repo_library();
+
+    let error = run(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/scripts/failing_assert.maid"),
+        None,
+    )
+    .unwrap_err();
+
+    assert_eq!(error.errors.len(), 1);
+    assert!(error.errors[0].text.contains("assert_eq failed: expected 3 but got 2"));
+}